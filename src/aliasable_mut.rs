@@ -0,0 +1,157 @@
+use crate::MaybeDanglingPtr;
+use ::core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::ManuallyDrop as StdMD,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+/// A <code>[&\'a mut T][reference]</code> without `noalias` (nor
+/// `dereferenceable`-ity): a mutable borrow which other (raw) pointers to the
+/// same memory may coexist with.
+/// This wrapper is 0-cost, and keeps the null-pointer niche.
+///
+/// Contrary to <code>[MaybeDanglingPtr]<[&\'a mut T][reference]></code>, it
+/// [`Deref`]s to `T` itself, and offers dedicated helpers to make it less
+/// clunky to use as a function argument:
+///
+/// ```rust
+/// use ::maybe_dangling::AliasableMut;
+///
+/// fn bump(mut counter: AliasableMut<'_, i32>) {
+///     *counter += 1;
+/// }
+///
+/// let mut x = 0;
+/// let mut counter = AliasableMut::from_mut(&mut x);
+/// let raw: *mut i32 = AliasableMut::as_mut_ptr(&mut counter);
+/// bump(AliasableMut::reborrow(&mut counter));
+/// unsafe { *raw += 1 }; // OK, `raw` is still usable…
+/// bump(AliasableMut::reborrow(&mut counter)); // …and so is `counter`.
+/// drop(counter);
+/// assert_eq!(x, 3);
+/// ```
+///
+/// Holding onto an [`AliasableMut`] (or passing it around) while other raw
+/// pointers to its pointee are in use is thus fine, as long as actual accesses
+/// do not overlap: whilst a <code>[&mut T][reference]</code> obtained out of
+/// it (_e.g._, through [`DerefMut`]) is alive, it is the only pointer which may
+/// be used.
+#[repr(transparent)]
+pub struct AliasableMut<'a, T: ?Sized> {
+    /// # SAFETY INVARIANT: stems from a `&'a mut T`.
+    ptr: NonNull<T>,
+    _borrows: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ?Sized> AliasableMut<'a, T> {
+    /// Strips a <code>[&mut T][reference]</code> of its `noalias`-ness.
+    #[inline]
+    pub fn from_mut(r: &'a mut T) -> AliasableMut<'a, T> {
+        Self {
+            ptr: NonNull::from(r),
+            _borrows: PhantomData,
+        }
+    }
+
+    /// Reborrows the [`AliasableMut`] for a shorter lifetime, so that it can be
+    /// handed to a function without giving it up.
+    #[inline]
+    pub fn reborrow<'r>(this: &'r mut AliasableMut<'a, T>) -> AliasableMut<'r, T> {
+        AliasableMut {
+            ptr: this.ptr,
+            _borrows: PhantomData,
+        }
+    }
+
+    /// Gets a proper <code>[&mut T][reference]</code> to the pointee, which
+    /// asserts uniqueness for as long as it is alive.
+    #[inline]
+    #[allow(clippy::should_implement_trait)] // associated function, on purpose.
+    pub fn as_mut<'r>(this: &'r mut AliasableMut<'a, T>) -> &'r mut T {
+        this
+    }
+
+    /// Gets a raw pointer to the pointee, without ever materializing a
+    /// <code>[&T][reference]</code>.
+    #[inline]
+    pub fn as_ptr(this: &AliasableMut<'a, T>) -> *const T {
+        this.ptr.as_ptr()
+    }
+
+    /// Gets a raw pointer to the pointee, without ever materializing a
+    /// <code>[&mut T][reference]</code>.
+    #[inline]
+    pub fn as_mut_ptr(this: &mut AliasableMut<'a, T>) -> *mut T {
+        this.ptr.as_ptr()
+    }
+
+    /// Converts the [`AliasableMut`] into a raw pointer.
+    #[inline]
+    pub fn into_raw(this: AliasableMut<'a, T>) -> *mut T {
+        this.ptr.as_ptr()
+    }
+}
+
+// Safety: the pointer stems from a `&'a mut T`, and `'a` is still alive.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> Deref for AliasableMut<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &Self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+// Safety: the pointer stems from a `&'a mut T`, and `'a` is still alive.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> DerefMut for AliasableMut<'a, T> {
+    #[inline]
+    fn deref_mut(self: &mut Self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+// Safety: same as for `&'a mut T`.
+#[allow(unsafe_code)]
+unsafe impl<'a, T: ?Sized + Send> Send for AliasableMut<'a, T> {}
+
+// Safety: same as for `&'a mut T`.
+#[allow(unsafe_code)]
+unsafe impl<'a, T: ?Sized + Sync> Sync for AliasableMut<'a, T> {}
+
+impl<'a, T: ?Sized + Debug> Debug for AliasableMut<'a, T> {
+    #[inline]
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a mut T> for AliasableMut<'a, T> {
+    #[inline]
+    fn from(r: &'a mut T) -> AliasableMut<'a, T> {
+        Self::from_mut(r)
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`
+// stemming from a `&'a mut T`.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> From<MaybeDanglingPtr<&'a mut T>> for AliasableMut<'a, T> {
+    #[inline]
+    fn from(ptr: MaybeDanglingPtr<&'a mut T>) -> AliasableMut<'a, T> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(ptr)) }
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`
+// stemming from a `&'a mut T`.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> From<AliasableMut<'a, T>> for MaybeDanglingPtr<&'a mut T> {
+    #[inline]
+    fn from(r: AliasableMut<'a, T>) -> MaybeDanglingPtr<&'a mut T> {
+        unsafe { ::core::mem::transmute_copy(&r) }
+    }
+}
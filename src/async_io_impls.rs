@@ -0,0 +1,247 @@
+//! Forwarding of the async I/O traits of `::tokio` and `::futures-io`, through
+//! pin projection.
+//!
+//! Like for `Future`, the [`ManuallyDrop`] impls require `T : Unpin`, since
+//! pinning is not structural for it (see [`ManuallyDrop::as_pin_mut()`]).
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use ::std::io;
+
+/// The `Unpin`-based projection, for `ManuallyDrop`.
+fn project_unpin<T: Unpin>(this: Pin<&mut ManuallyDrop<T>>) -> Pin<&mut T> {
+    Pin::new(&mut **this.get_mut())
+}
+
+macro_rules! PinProjectTM {
+    (
+        $(
+            $(#$attr:tt)*
+            impl<T: $Bound:path> $($Trait:ident)::+ for _ {
+                $($inner:tt)*
+            }
+        )*
+    ) => (
+        $(
+            $(#$attr)*
+            impl<T: $Bound> $($Trait)::+ for MaybeDangling<T> {
+                PinProjectTM! {
+                    @project[MaybeDangling::as_pin_mut]
+                    $($inner)*
+                }
+            }
+
+            $(#$attr)*
+            impl<T: $Bound + Unpin> $($Trait)::+ for ManuallyDrop<T> {
+                PinProjectTM! {
+                    @project[project_unpin]
+                    $($inner)*
+                }
+            }
+        )*
+    );
+
+    (
+        @project[$project:path]
+    ) => ();
+
+    (
+        @project[$project:path]
+        fn $fname:ident(
+            self: Pin<&mut Self> $(,
+            $arg_name:ident : $ArgTy:ty )* $(,)?
+        ) $(-> $Ret:ty)? ;
+        $($rest:tt)*
+    ) => (
+        #[inline]
+        fn $fname(
+            self: Pin<&mut Self>,
+            $(
+                $arg_name : $ArgTy
+            ),*
+        ) $(-> $Ret)?
+        {
+            T::$fname($project(self), $($arg_name),*)
+        }
+
+        PinProjectTM! {
+            @project[$project]
+            $($rest)*
+        }
+    );
+
+    (
+        @project[$project:path]
+        fn $fname:ident(
+            self: &Self $(,
+            $arg_name:ident : $ArgTy:ty )* $(,)?
+        ) $(-> $Ret:ty)? ;
+        $($rest:tt)*
+    ) => (
+        #[inline]
+        fn $fname(
+            self: &Self,
+            $(
+                $arg_name : $ArgTy
+            ),*
+        ) $(-> $Ret)?
+        {
+            T::$fname(self, $($arg_name),*)
+        }
+
+        PinProjectTM! {
+            @project[$project]
+            $($rest)*
+        }
+    );
+}
+
+#[cfg(feature = "tokio")]
+PinProjectTM! {
+    /// ```rust
+    /// use ::core::{pin::Pin, task::{Context, Poll}};
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    /// use ::tokio::io::{AsyncRead, ReadBuf};
+    ///
+    /// # fn noop_waker() -> ::core::task::Waker {
+    /// #     use ::core::task::{RawWaker, RawWakerVTable, Waker};
+    /// #     const VTABLE: RawWakerVTable = RawWakerVTable::new(
+    /// #         |_| RawWaker::new(::core::ptr::null(), &VTABLE),
+    /// #         |_| {},
+    /// #         |_| {},
+    /// #         |_| {},
+    /// #     );
+    /// #     unsafe { Waker::from_raw(RawWaker::new(::core::ptr::null(), &VTABLE)) }
+    /// # }
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    /// let mut storage = [0; 8];
+    /// let mut buf = ReadBuf::new(&mut storage);
+    ///
+    /// let mut reader = MaybeDangling::new(&b"hi"[..]);
+    /// let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+    /// assert!(matches!(poll, Poll::Ready(Ok(()))));
+    /// let mut reader = ManuallyDrop::new(&b"!"[..]);
+    /// let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+    /// assert!(matches!(poll, Poll::Ready(Ok(()))));
+    /// assert_eq!(buf.filled(), b"hi!");
+    /// ```
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "tokio")))]
+    impl<T: ::tokio::io::AsyncRead> tokio::io::AsyncRead for _ {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ::tokio::io::ReadBuf<'_>,
+        ) -> Poll<io::Result<()>>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "tokio")))]
+    impl<T: ::tokio::io::AsyncBufRead> tokio::io::AsyncBufRead for _ {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>>;
+        fn consume(self: Pin<&mut Self>, amt: usize);
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "tokio")))]
+    impl<T: ::tokio::io::AsyncWrite> tokio::io::AsyncWrite for _ {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>>;
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[io::IoSlice<'_>],
+        ) -> Poll<io::Result<usize>>;
+        fn is_write_vectored(self: &Self) -> bool;
+    }
+}
+
+#[cfg(feature = "futures-io")]
+PinProjectTM! {
+    /// ```rust
+    /// use ::core::{pin::Pin, task::{Context, Poll}};
+    /// use ::futures_io::AsyncRead;
+    /// use ::maybe_dangling::MaybeDangling;
+    ///
+    /// # fn noop_waker() -> ::core::task::Waker {
+    /// #     use ::core::task::{RawWaker, RawWakerVTable, Waker};
+    /// #     const VTABLE: RawWakerVTable = RawWakerVTable::new(
+    /// #         |_| RawWaker::new(::core::ptr::null(), &VTABLE),
+    /// #         |_| {},
+    /// #         |_| {},
+    /// #         |_| {},
+    /// #     );
+    /// #     unsafe { Waker::from_raw(RawWaker::new(::core::ptr::null(), &VTABLE)) }
+    /// # }
+    /// struct Ones;
+    ///
+    /// impl AsyncRead for Ones {
+    ///     fn poll_read(
+    ///         self: Pin<&mut Self>,
+    ///         _: &mut Context<'_>,
+    ///         buf: &mut [u8],
+    ///     ) -> Poll<::std::io::Result<usize>> {
+    ///         buf.fill(1);
+    ///         Poll::Ready(Ok(buf.len()))
+    ///     }
+    /// }
+    ///
+    /// let waker = noop_waker();
+    /// let mut cx = Context::from_waker(&waker);
+    /// let mut buf = [0; 3];
+    /// let mut reader = MaybeDangling::new(Ones);
+    /// let poll = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+    /// assert!(matches!(poll, Poll::Ready(Ok(3))));
+    /// assert_eq!(buf, [1, 1, 1]);
+    /// ```
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-io")))]
+    impl<T: ::futures_io::AsyncRead> futures_io::AsyncRead for _ {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>>;
+        fn poll_read_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &mut [io::IoSliceMut<'_>],
+        ) -> Poll<io::Result<usize>>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-io")))]
+    impl<T: ::futures_io::AsyncBufRead> futures_io::AsyncBufRead for _ {
+        fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>>;
+        fn consume(self: Pin<&mut Self>, amt: usize);
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-io")))]
+    impl<T: ::futures_io::AsyncWrite> futures_io::AsyncWrite for _ {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>>;
+        fn poll_write_vectored(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            bufs: &[io::IoSlice<'_>],
+        ) -> Poll<io::Result<usize>>;
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-io")))]
+    impl<T: ::futures_io::AsyncSeek> futures_io::AsyncSeek for _ {
+        fn poll_seek(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            pos: io::SeekFrom,
+        ) -> Poll<io::Result<u64>>;
+    }
+}
@@ -0,0 +1,44 @@
+//! Heterogeneous `PartialEq` and `PartialOrd` impls: see the "Comparisons"
+//! section of the docs of [`ManuallyDrop`].
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::{cmp::Ordering, mem::ManuallyDrop as StdMD};
+
+macro_rules! PartialEqOrdTM {(
+    $(
+        impl<T> _<$Rhs:ty> for $Lhs:ty where T : _<$Other:ty>;
+    )*
+) => (
+    $(
+        impl<T> PartialEq<$Rhs> for $Lhs
+        where
+            T : PartialEq<$Other>,
+        {
+            #[inline]
+            fn eq(self: &Self, other: &$Rhs) -> bool {
+                T::eq(self, other)
+            }
+        }
+
+        impl<T> PartialOrd<$Rhs> for $Lhs
+        where
+            T : PartialOrd<$Other>,
+        {
+            #[inline]
+            fn partial_cmp(self: &Self, other: &$Rhs) -> Option<Ordering> {
+                T::partial_cmp(self, other)
+            }
+        }
+    )*
+)}
+
+PartialEqOrdTM! {
+    impl<T> _<T> for ManuallyDrop<T> where T : _<T>;
+    impl<T> _<T> for MaybeDangling<T> where T : _<T>;
+
+    impl<T> _<MaybeDangling<T>> for ManuallyDrop<T> where T : _<T>;
+    impl<T> _<ManuallyDrop<T>> for MaybeDangling<T> where T : _<T>;
+
+    impl<T> _<StdMD<T>> for ManuallyDrop<T> where T : _<T>;
+    impl<T> _<ManuallyDrop<T>> for StdMD<T> where T : _<T>;
+}
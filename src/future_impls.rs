@@ -0,0 +1,107 @@
+//! `Future` (and, with the `futures-core` feature, `Stream`) impls, through
+//! pin projection.
+//!
+//! For [`MaybeDangling`], pinning is structural, so these are forwarded as
+//! is. For [`ManuallyDrop`], it is not (see [`ManuallyDrop::as_pin_mut()`]),
+//! so these require `T : Unpin`.
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// ```rust
+/// use ::core::future::Future;
+/// use ::maybe_dangling::MaybeDangling;
+///
+/// async fn wrapped(fut: impl Future<Output = i32>) -> i32 {
+///     MaybeDangling::new(fut).await
+/// }
+/// ```
+impl<F: Future> Future for MaybeDangling<F> {
+    type Output = F::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        F::poll(self.as_pin_mut(), cx)
+    }
+}
+
+impl<F: Future + Unpin> Future for ManuallyDrop<F> {
+    type Output = F::Output;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        F::poll(Pin::new(&mut **self.get_mut()), cx)
+    }
+}
+
+#[cfg(feature = "futures-core")]
+mod futures_core {
+    use super::*;
+    use ::futures_core::{FusedFuture, FusedStream, Stream};
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<F: FusedFuture> FusedFuture for MaybeDangling<F> {
+        #[inline]
+        fn is_terminated(self: &Self) -> bool {
+            F::is_terminated(self)
+        }
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<F: FusedFuture + Unpin> FusedFuture for ManuallyDrop<F> {
+        #[inline]
+        fn is_terminated(self: &Self) -> bool {
+            F::is_terminated(self)
+        }
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<S: Stream> Stream for MaybeDangling<S> {
+        type Item = S::Item;
+
+        #[inline]
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+            S::poll_next(self.as_pin_mut(), cx)
+        }
+
+        #[inline]
+        fn size_hint(self: &Self) -> (usize, Option<usize>) {
+            S::size_hint(self)
+        }
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<S: Stream + Unpin> Stream for ManuallyDrop<S> {
+        type Item = S::Item;
+
+        #[inline]
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+            S::poll_next(Pin::new(&mut **self.get_mut()), cx)
+        }
+
+        #[inline]
+        fn size_hint(self: &Self) -> (usize, Option<usize>) {
+            S::size_hint(self)
+        }
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<S: FusedStream> FusedStream for MaybeDangling<S> {
+        #[inline]
+        fn is_terminated(self: &Self) -> bool {
+            S::is_terminated(self)
+        }
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "futures-core")))]
+    impl<S: FusedStream + Unpin> FusedStream for ManuallyDrop<S> {
+        #[inline]
+        fn is_terminated(self: &Self) -> bool {
+            S::is_terminated(self)
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Collection-oriented impls: see the "Collections" section of the docs of
+//! [`ManuallyDrop`].
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::iter::{FromIterator, Product, Sum};
+
+macro_rules! IterTM {(
+    $(
+        for $W:ident;
+    )*
+) => (
+    $(
+        impl<T: FromIterator<A>, A> FromIterator<A> for $W<T> {
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> $W<T> {
+                $W::new(T::from_iter(iter))
+            }
+        }
+
+        impl<T: Extend<A>, A> Extend<A> for $W<T> {
+            #[inline]
+            fn extend<I: IntoIterator<Item = A>>(self: &mut $W<T>, iter: I) {
+                T::extend(self, iter)
+            }
+        }
+
+        impl<'r, T> IntoIterator for &'r $W<T>
+        where
+            &'r T: IntoIterator,
+        {
+            type Item = <&'r T as IntoIterator>::Item;
+            type IntoIter = <&'r T as IntoIterator>::IntoIter;
+
+            #[inline]
+            fn into_iter(self: &'r $W<T>) -> Self::IntoIter {
+                <&'r T>::into_iter(&**self)
+            }
+        }
+
+        impl<T: Sum<A>, A> Sum<A> for $W<T> {
+            #[inline]
+            fn sum<I: Iterator<Item = A>>(iter: I) -> $W<T> {
+                $W::new(T::sum(iter))
+            }
+        }
+
+        impl<T: Product<A>, A> Product<A> for $W<T> {
+            #[inline]
+            fn product<I: Iterator<Item = A>>(iter: I) -> $W<T> {
+                $W::new(T::product(iter))
+            }
+        }
+    )*
+)}
+
+IterTM! {
+    for ManuallyDrop;
+    for MaybeDangling;
+}
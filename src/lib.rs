@@ -1,14 +1,62 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 #![deny(unsafe_code)]
+#![allow(clippy::needless_arbitrary_self_type)]
 #![cfg_attr(feature = "nightly-dropck_eyepatch", feature(dropck_eyepatch))]
+#![cfg_attr(feature = "better-docs", feature(doc_cfg))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
 
 pub use self::maybe_dangling::MaybeDangling;
 mod maybe_dangling;
 
+#[cfg(feature = "alloc")]
+pub use self::maybe_dangling_box::MaybeDanglingBox;
+#[cfg(feature = "alloc")]
+mod maybe_dangling_box;
+
+pub use self::aliasable_mut::AliasableMut;
+mod aliasable_mut;
+
+pub use self::maybe_dangling_ref::MaybeDanglingRef;
+mod maybe_dangling_ref;
+pub use self::maybe_dangling_copy::MaybeDanglingCopy;
+mod maybe_dangling_copy;
+
+pub use self::maybe_dangling_ptr::{MaybeDanglingPtr, PointerLike};
+mod maybe_dangling_ptr;
+
 pub use manually_drop::ManuallyDrop;
 mod manually_drop;
 
+#[cfg(any(feature = "tokio", feature = "futures-io"))]
+mod async_io_impls;
+
+mod cmp_impls;
+
+mod future_impls;
+
+mod iter_impls;
+
+mod ops_impls;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "std")]
+mod std_impls;
+
+/// The helper traits of the tuple transpositions, such as
+/// [`MaybeDangling::transpose_tuple()`].
+pub mod transpose {
+    pub use crate::transpose_impls::{Tuple, TupleOfWrappers};
+}
+mod transpose_impls;
+
 #[rustfmt::skip]
 /// I really don't get the complexity of `cfg_if!`…
 macro_rules! match_cfg {
@@ -2,8 +2,11 @@ use ::core::{
     cmp::*,
     fmt::{self, Debug},
     hash::{self, Hash},
-    mem::MaybeUninit as MU,
+    iter,
+    mem::{ManuallyDrop as StdMD, MaybeUninit as MU},
     ops::{Deref, DerefMut},
+    pin::Pin,
+    ptr::NonNull,
 };
 
 /// A wrapper to inhibit compiler from automatically calling `T`’s destructor.
@@ -28,11 +31,34 @@ use ::core::{
 ///   - It does not implement
 ///     [`Structural{Partial,}Eq`][::core::marker::StructuralEq].
 ///
+///   - **`T : Sized` is required**
+///
+///     Contrary to <code>[::core::mem::ManuallyDrop]\<T : ?Sized\></code>,
+///     the `T` here has to be `Sized`, since [`::core::mem::MaybeUninit`],
+///     which this implementation relies on, does not support unsized types.
+///
+///     This means that neither `ManuallyDrop<[T]>`, `ManuallyDrop<str>`, nor
+///     `ManuallyDrop<dyn Trait>` can be written, and that there are no
+///     unsizing coercions from, say, `&ManuallyDrop<[T; N]>` to
+///     `&ManuallyDrop<[T]>`. The same applies to [`crate::MaybeDangling`].
+///
+///     Note that an unsized value can never be moved around by value, only
+///     ever behind some pointer, so the pointer is the one which ought to be
+///     wrapped: _e.g._, `MaybeDangling<Box<dyn Future>>` rather than
+///     `Box<MaybeDangling<dyn Future>>`.
+///
 ///   - Note that once stdlib's own [`::core::mem::ManuallyDrop`] properly gets
 ///     its aliasing/`dereferenceable`ity properties removed, this crate shall
 ///     be updated to just reëxport it (using a `build.rs` to prevent MSRV
 ///     breakage).
 ///
+///     Recent nightlies have started doing so (and offer
+///     `::core::mem::MaybeDangling` behind `#![feature(maybe_dangling)]`), but
+///     no stable Rust release has yet. Moreover, the reëxported type would lack
+///     the inherent API this crate's `ManuallyDrop` offers (_e.g._, the slice
+///     casts), so the switch cannot be a mere compiler-dependent `cfg`: it
+///     will be a semver-breaking release, once such a stable Rust exists.
+///
 ///     This means that the _lack of discriminant elision_ cannot be relied upon
 ///     either!
 ///
@@ -41,6 +67,74 @@ use ::core::{
 ///       - equal [`Layout`][::core::alloc::Layout];
 ///       - equal calling-convention ABI[^1]
 ///
+/// # Comparisons
+///
+/// Both this wrapper and [`crate::MaybeDangling`] can be compared against bare
+/// values, or against other wrappers, without having to `*`-dereference them
+/// first:
+///
+/// ```rust
+/// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+///
+/// let x = MaybeDangling::new(42);
+/// assert!(x == 42 && x < 43);
+/// assert!(ManuallyDrop::new(42) == x);
+/// assert!(ManuallyDrop::new(42) == ::core::mem::ManuallyDrop::new(42));
+/// ```
+///
+/// Note that the orphan rules make the mirrored `T == MaybeDangling<T>`
+/// comparisons impossible to write for a generic `T`: the wrapper has to be
+/// on the left-hand side.
+///
+/// Also, the wrapper-to-wrapper comparisons are only provided for a same `T`
+/// payload, since a `ManuallyDrop<T> : PartialEq<MaybeDangling<U>>` impl
+/// would overlap with the `ManuallyDrop<T> : PartialEq<T>` one (for
+/// `T = MaybeDangling<U>`).
+///
+/// # Operators
+///
+/// Both this wrapper and [`crate::MaybeDangling`] forward the [`::core::ops`]
+/// operators, in the fashion of [`::core::num::Wrapping`]:
+///
+///   - binary operators combine two wrappers (by value or by reference), and
+///     rewrap the output;
+///   - except for shifts, whose right-hand side is a bare value;
+///   - the compound-assignment operators take a wrapper (by value or by
+///     reference), or a bare value for shifts.
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDangling;
+///
+/// let mut flags = MaybeDangling::new(0b0011_u8);
+/// flags |= MaybeDangling::new(0b0100);
+/// flags <<= 1;
+/// assert_eq!(*(&flags & &MaybeDangling::new(0b1010)), 0b1010);
+/// assert_eq!(*-MaybeDangling::new(1_i32), -1);
+/// ```
+///
+/// Note that, for [`ManuallyDrop`], the by-value operators take the `T`
+/// values out of their wrappers, so it is up to the `T`-level operator
+/// whether they get dropped.
+///
+/// # Collections
+///
+/// Both this wrapper and [`crate::MaybeDangling`] can be `.collect()`ed into,
+/// [`Extend`]ed, and iterated by reference:
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDangling;
+///
+/// let s = String::from("dangle");
+/// let mut v: MaybeDangling<Vec<&str>> = s.split('a').collect();
+/// v.extend(["!"]);
+/// assert_eq!((&v).into_iter().count(), 3);
+/// ```
+///
+/// Note that `IntoIterator` is only implemented for `&Self`: both `Self` and
+/// `&mut Self` are already `IntoIterator` when `T : Iterator`, through
+/// `Iterator` forwarding, and `core`'s blanket impls, which would thus
+/// overlap.
+///
 /// [^1]: this is assuming `MaybeUninit<T>` has the same ABI as `T`, as it
 /// currently advertises, despite that probably being a bad idea for
 /// a "bag of bytes" `T`-ish wrapper, since it means that padding bytes
@@ -86,6 +180,10 @@ impl<T> ManuallyDrop<T> {
     /// Takes the value from the `ManuallyDrop<T>` container out.
     ///
     /// See [`::core::mem::ManuallyDrop::take()`] for more info.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`::core::mem::ManuallyDrop::take()`].
     #[must_use = "if you don't need the value, you can use `ManuallyDrop::drop` instead"]
     #[inline]
     pub unsafe fn take(slot: &mut ManuallyDrop<T>) -> T {
@@ -95,10 +193,401 @@ impl<T> ManuallyDrop<T> {
     /// Manually drops the contained value.
     ///
     /// See [`::core::mem::ManuallyDrop::drop()`] for more info.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`::core::mem::ManuallyDrop::drop()`].
     #[inline]
     pub unsafe fn drop(slot: &mut ManuallyDrop<T>) {
         unsafe { slot.value.as_mut_ptr().drop_in_place() }
     }
+
+    /// Converts the `ManuallyDrop<T>` into its inner [`MaybeUninit<T>`][MU]
+    /// storage, at no cost.
+    #[inline]
+    pub const fn into_maybe_uninit(slot: ManuallyDrop<T>) -> MU<T> {
+        slot.value
+    }
+
+    /// Adopts an init [`MaybeUninit<T>`][MU] storage, at no cost.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`MaybeUninit::assume_init()`][MU::assume_init()]: `value` must be init.
+    #[inline]
+    pub const unsafe fn assume_init_from(value: MU<T>) -> ManuallyDrop<T> {
+        Self { value }
+    }
+
+    /// Views the value as its inner [`MaybeUninit<T>`][MU] storage.
+    #[inline]
+    pub const fn as_maybe_uninit(this: &ManuallyDrop<T>) -> &MU<T> {
+        &this.value
+    }
+
+    /// Views the value as its inner [`MaybeUninit<T>`][MU] storage.
+    ///
+    /// # Safety
+    ///
+    /// The storage must be left init (as far as `MU` is concerned) when the
+    /// returned borrow ends.
+    #[inline]
+    pub unsafe fn as_maybe_uninit_mut(this: &mut ManuallyDrop<T>) -> &mut MU<T> {
+        &mut this.value
+    }
+
+    /// Gets a raw pointer to the value, without ever materializing a `&T`
+    /// (contrary to `&*this as *const T`), which would assert its validity.
+    #[inline]
+    pub const fn as_ptr(this: &ManuallyDrop<T>) -> *const T {
+        this.value.as_ptr()
+    }
+
+    /// Gets a raw pointer to the value, without ever materializing a `&mut T`
+    /// (contrary to `&mut *this as *mut T`), which would assert its validity.
+    #[inline]
+    pub fn as_mut_ptr(this: &mut ManuallyDrop<T>) -> *mut T {
+        this.value.as_mut_ptr()
+    }
+
+    /// Same as [`ManuallyDrop::as_mut_ptr()`], but as a [`NonNull`].
+    #[inline]
+    pub fn as_non_null(this: &mut ManuallyDrop<T>) -> NonNull<T> {
+        // Safety: stems from a reference.
+        unsafe { NonNull::new_unchecked(Self::as_mut_ptr(this)) }
+    }
+
+    /// Casts a `*const T` to a `*const ManuallyDrop<T>`, thanks to the
+    /// `#[repr(transparent)]` layout.
+    ///
+    /// This is safe, as any pointer cast, but dereferencing the result
+    /// requires the same care as dereferencing `ptr` itself.
+    ///
+    /// ```rust
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    /// use ::std::rc::Rc;
+    ///
+    /// let rc = Rc::new(42);
+    /// let mut md = ManuallyDrop::new(Rc::clone(&rc));
+    /// assert_eq!(ManuallyDrop::as_ptr(&md), &*md as *const Rc<i32>);
+    /// // Safety: the value is init, and it is not used afterwards.
+    /// unsafe { ManuallyDrop::as_mut_ptr(&mut md).drop_in_place() };
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    ///
+    /// let mut md = MaybeDangling::new(Rc::clone(&rc));
+    /// let ptr = MaybeDangling::as_non_null(&mut md);
+    /// assert_eq!(unsafe { **ptr.as_ptr() }, 42);
+    ///
+    /// let mut value = 0_u8;
+    /// let p: *mut ManuallyDrop<u8> = ManuallyDrop::from_mut_ptr(&mut value);
+    /// unsafe { **p += 1 };
+    /// let p: *const MaybeDangling<u8> = MaybeDangling::from_ptr(&value);
+    /// assert_eq!(unsafe { **p }, 1);
+    /// drop(md);
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    /// ```
+    #[inline]
+    pub const fn from_ptr(ptr: *const T) -> *const ManuallyDrop<T> {
+        ptr as _
+    }
+
+    /// Casts a `*mut T` to a `*mut ManuallyDrop<T>`, thanks to the
+    /// `#[repr(transparent)]` layout.
+    ///
+    /// See [`ManuallyDrop::from_ptr()`] for more info.
+    #[inline]
+    pub const fn from_mut_ptr(ptr: *mut T) -> *mut ManuallyDrop<T> {
+        ptr as _
+    }
+
+    /// Maps the value, keeping it wrapped in a `ManuallyDrop`.
+    ///
+    /// Note that `f` gets the `T` by value, and thus asserts its validity.
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(slot: ManuallyDrop<T>, f: F) -> ManuallyDrop<U> {
+        ManuallyDrop::new(f(Self::into_inner(slot)))
+    }
+
+    /// Replaces the value, returning the old one, without ever materializing a
+    /// `&mut T` (contrary to `mem::replace(&mut *slot, value)`).
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ManuallyDrop::take()`]: the value must not have been taken
+    /// out or dropped already.
+    #[inline]
+    pub unsafe fn replace(slot: &mut ManuallyDrop<T>, value: T) -> T {
+        let prev = unsafe { slot.value.as_ptr().read() };
+        slot.value = MU::new(value);
+        prev
+    }
+
+    /// Takes the value out if `predicate` returns `true` for it.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ManuallyDrop::take()`], should the value be taken out (_i.e._,
+    /// should `Some` be returned).
+    #[inline]
+    pub unsafe fn take_if<F: FnOnce(&T) -> bool>(
+        slot: &mut ManuallyDrop<T>,
+        predicate: F,
+    ) -> Option<T> {
+        if predicate(slot) {
+            Some(unsafe { Self::take(slot) })
+        } else {
+            None
+        }
+    }
+
+    /// Pin projection: from a pinned `ManuallyDrop<T>` to a pinned `T`.
+    ///
+    /// # Safety
+    ///
+    /// Since a `ManuallyDrop<T>` does not drop its contents, pinning is not
+    /// structural for it: the `Drop` guarantee of [`Pin`] would be violated if
+    /// the memory of the `ManuallyDrop<T>` were to be invalidated or
+    /// repurposed without having dropped the `T` in place beforehand (_e.g._,
+    /// using [`ManuallyDrop::drop()`]). The caller must prevent that from
+    /// happening (unless `T : Unpin`).
+    ///
+    /// They must also not move the `T` out (_e.g._, using
+    /// [`ManuallyDrop::take()`] or [`ManuallyDrop::into_inner()`]) once it is
+    /// pinned.
+    ///
+    /// See [`crate::MaybeDangling::as_pin_ref()`] for a safe alternative.
+    #[inline]
+    pub unsafe fn as_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        unsafe { self.map_unchecked(|this| &**this) }
+    }
+
+    /// Pin projection: from a pinned `ManuallyDrop<T>` to a pinned `T`.
+    ///
+    /// # Safety
+    ///
+    /// See [`Self::as_pin_ref()`].
+    #[inline]
+    pub unsafe fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        unsafe { self.map_unchecked_mut(|this| &mut **this) }
+    }
+}
+
+impl<T> ManuallyDrop<T> {
+    /// Re-arms the drop glue: converts into a [`crate::MaybeDangling`], at no
+    /// cost.
+    #[inline]
+    pub fn into_maybe_dangling(slot: ManuallyDrop<T>) -> crate::MaybeDangling<T> {
+        #![allow(unsafe_code)]
+        // Safety: `MaybeDangling<T>` is a `#[repr(transparent)]` wrapper
+        // around a `ManuallyDrop<T>`.
+        unsafe { ::core::mem::transmute_copy(&slot) }
+    }
+
+    /// Views the `ManuallyDrop<T>` as a [`crate::MaybeDangling`], in place.
+    ///
+    /// # Safety
+    ///
+    /// The returned `&mut MaybeDangling<T>` may be used to drop the value
+    /// (_e.g._, by overwriting it), so the caller must make sure that this is
+    /// fine, as with [`ManuallyDrop::drop()`].
+    #[inline]
+    pub unsafe fn as_maybe_dangling_mut(
+        this: &mut ManuallyDrop<T>,
+    ) -> &mut crate::MaybeDangling<T> {
+        #![allow(unsafe_code)]
+        // Safety: `MaybeDangling<T>` is a `#[repr(transparent)]` wrapper
+        // around a `ManuallyDrop<T>`.
+        unsafe { &mut *(this as *mut ManuallyDrop<T> as *mut crate::MaybeDangling<T>) }
+    }
+}
+
+/// Zero-cost reference, slice and array casts, thanks to the
+/// `#[repr(transparent)]` layout.
+///
+/// These are sound in both directions, since the `MU<T>` inside a
+/// `ManuallyDrop<T>` is always init, and since only init `T`s can be written
+/// through a `&mut ManuallyDrop<T>` (the previous value then being leaked).
+///
+/// Only the `&`-based ones are `const`, since `&mut` in `const fn`s requires
+/// a more recent Rust version than our MSRV.
+///
+/// ```rust
+/// use ::maybe_dangling::ManuallyDrop;
+///
+/// let mut v = vec![String::from("a"), String::from("b")];
+/// let slice = ManuallyDrop::slice_from_mut(&mut v[..]);
+/// slice[0].push('c');
+/// assert_eq!(ManuallyDrop::slice_as_inner(slice), ["ac", "b"]);
+/// // Safety: the elements are not used afterwards…
+/// unsafe { ManuallyDrop::drop_slice_in_place(slice) };
+/// // … not even by the `Vec`'s own drop glue.
+/// unsafe { v.set_len(0) };
+///
+/// let mut array = [1, 2, 3];
+/// let mds: &mut [ManuallyDrop<i32>; 3] = ManuallyDrop::array_from_mut(&mut array);
+/// *mds[1] *= 10;
+/// assert_eq!(ManuallyDrop::array_as_inner(mds), &[1, 20, 3]);
+/// ```
+#[allow(unsafe_code)]
+impl<T> ManuallyDrop<T> {
+    /// Views a `&T` as a `&ManuallyDrop<T>`.
+    #[inline]
+    pub const fn from_ref(r: &T) -> &ManuallyDrop<T> {
+        unsafe { &*(r as *const T as *const ManuallyDrop<T>) }
+    }
+
+    /// Views a `&mut T` as a `&mut ManuallyDrop<T>`.
+    #[inline]
+    pub fn from_mut(r: &mut T) -> &mut ManuallyDrop<T> {
+        unsafe { &mut *(r as *mut T as *mut ManuallyDrop<T>) }
+    }
+
+    /// Views a `&[T]` as a `&[ManuallyDrop<T>]`.
+    #[inline]
+    pub const fn slice_from_ref(slice: &[T]) -> &[ManuallyDrop<T>] {
+        unsafe { &*(slice as *const [T] as *const [ManuallyDrop<T>]) }
+    }
+
+    /// Views a `&mut [T]` as a `&mut [ManuallyDrop<T>]`.
+    #[inline]
+    pub fn slice_from_mut(slice: &mut [T]) -> &mut [ManuallyDrop<T>] {
+        unsafe { &mut *(slice as *mut [T] as *mut [ManuallyDrop<T>]) }
+    }
+
+    /// Views a `&[ManuallyDrop<T>]` as a `&[T]`.
+    #[inline]
+    pub const fn slice_as_inner(slice: &[ManuallyDrop<T>]) -> &[T] {
+        unsafe { &*(slice as *const [ManuallyDrop<T>] as *const [T]) }
+    }
+
+    /// Views a `&mut [ManuallyDrop<T>]` as a `&mut [T]`.
+    #[inline]
+    pub fn slice_as_inner_mut(slice: &mut [ManuallyDrop<T>]) -> &mut [T] {
+        unsafe { &mut *(slice as *mut [ManuallyDrop<T>] as *mut [T]) }
+    }
+
+    /// Views a `&[T; N]` as a `&[ManuallyDrop<T>; N]`.
+    #[inline]
+    pub const fn array_from_ref<const N: usize>(array: &[T; N]) -> &[ManuallyDrop<T>; N] {
+        unsafe { &*(array as *const [T; N] as *const [ManuallyDrop<T>; N]) }
+    }
+
+    /// Views a `&mut [T; N]` as a `&mut [ManuallyDrop<T>; N]`.
+    #[inline]
+    pub fn array_from_mut<const N: usize>(array: &mut [T; N]) -> &mut [ManuallyDrop<T>; N] {
+        unsafe { &mut *(array as *mut [T; N] as *mut [ManuallyDrop<T>; N]) }
+    }
+
+    /// Views a `&[ManuallyDrop<T>; N]` as a `&[T; N]`.
+    #[inline]
+    pub const fn array_as_inner<const N: usize>(array: &[ManuallyDrop<T>; N]) -> &[T; N] {
+        unsafe { &*(array as *const [ManuallyDrop<T>; N] as *const [T; N]) }
+    }
+
+    /// Views a `&mut [ManuallyDrop<T>; N]` as a `&mut [T; N]`.
+    #[inline]
+    pub fn array_as_inner_mut<const N: usize>(array: &mut [ManuallyDrop<T>; N]) -> &mut [T; N] {
+        unsafe { &mut *(array as *mut [ManuallyDrop<T>; N] as *mut [T; N]) }
+    }
+
+    /// Manually drops all the values of a slice, in place.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`ManuallyDrop::drop()`], for each element of the slice.
+    #[inline]
+    pub unsafe fn drop_slice_in_place(slice: &mut [ManuallyDrop<T>]) {
+        unsafe { (slice as *mut [ManuallyDrop<T>] as *mut [T]).drop_in_place() }
+    }
+}
+
+/// Interop with [`::core::mem::ManuallyDrop`].
+///
+/// Beware that the `core` type does not feature the `MaybeDangling`
+/// semantics: once converted to it (or viewed as it), the value is, again,
+/// asserted to be fully valid (_e.g._, `dereferenceable`) whenever it is used.
+///
+/// ```rust
+/// use ::maybe_dangling::ManuallyDrop;
+///
+/// let mut std_md = ::core::mem::ManuallyDrop::new(String::from("a"));
+/// ManuallyDrop::from_std_mut(&mut std_md).push('b');
+/// assert_eq!(*std_md, "ab");
+/// assert_eq!(**ManuallyDrop::from_std_ref(&std_md), "ab");
+///
+/// let mut md = ManuallyDrop::from_std(std_md);
+/// ManuallyDrop::as_std_mut(&mut md).push('c');
+/// assert_eq!(**ManuallyDrop::as_std(&md), "abc");
+///
+/// let mut std_md = ManuallyDrop::into_std(md);
+/// unsafe { ::core::mem::ManuallyDrop::drop(&mut std_md) }
+/// ```
+impl<T> ManuallyDrop<T> {
+    /// Converts a [`::core::mem::ManuallyDrop`] into this crate's.
+    #[inline]
+    pub const fn from_std(slot: StdMD<T>) -> ManuallyDrop<T> {
+        Self::new(StdMD::into_inner(slot))
+    }
+
+    /// Converts this crate's `ManuallyDrop` into a [`::core::mem::ManuallyDrop`].
+    #[inline]
+    pub const fn into_std(slot: ManuallyDrop<T>) -> StdMD<T> {
+        StdMD::new(Self::into_inner(slot))
+    }
+
+    /// Views a <code>&[::core::mem::ManuallyDrop]\<T\></code> as a
+    /// <code>&[ManuallyDrop]\<T\></code>, at no cost.
+    #[inline]
+    pub const fn from_std_ref(r: &StdMD<T>) -> &ManuallyDrop<T> {
+        #![allow(unsafe_code)]
+        // Safety: both are `#[repr(transparent)]` wrappers around a `T`, and
+        // an init `T` is an init `MU<T>`.
+        unsafe { &*(r as *const StdMD<T> as *const ManuallyDrop<T>) }
+    }
+
+    /// Views a <code>&mut [::core::mem::ManuallyDrop]\<T\></code> as a
+    /// <code>&mut [ManuallyDrop]\<T\></code>, at no cost.
+    #[inline]
+    pub fn from_std_mut(r: &mut StdMD<T>) -> &mut ManuallyDrop<T> {
+        #![allow(unsafe_code)]
+        // Safety: same as for `from_std_ref()`; moreover, our invariant
+        // prevents an uninit `MU<T>` from being written through the result.
+        unsafe { &mut *(r as *mut StdMD<T> as *mut ManuallyDrop<T>) }
+    }
+
+    /// Views a <code>&[ManuallyDrop]\<T\></code> as a
+    /// <code>&[::core::mem::ManuallyDrop]\<T\></code>, at no cost.
+    #[inline]
+    pub const fn as_std(this: &ManuallyDrop<T>) -> &StdMD<T> {
+        #![allow(unsafe_code)]
+        // Safety: both are `#[repr(transparent)]` wrappers around a `T`, and
+        // our `MU<T>` is always init.
+        unsafe { &*(this as *const ManuallyDrop<T> as *const StdMD<T>) }
+    }
+
+    /// Views a <code>&mut [ManuallyDrop]\<T\></code> as a
+    /// <code>&mut [::core::mem::ManuallyDrop]\<T\></code>, at no cost.
+    #[inline]
+    pub fn as_std_mut(this: &mut ManuallyDrop<T>) -> &mut StdMD<T> {
+        #![allow(unsafe_code)]
+        // Safety: same as for `as_std()`; moreover, only init `T`s can be
+        // written through the result.
+        unsafe { &mut *(this as *mut ManuallyDrop<T> as *mut StdMD<T>) }
+    }
+}
+
+impl<T> From<StdMD<T>> for ManuallyDrop<T> {
+    #[inline]
+    fn from(slot: StdMD<T>) -> ManuallyDrop<T> {
+        Self::from_std(slot)
+    }
+}
+
+impl<T> From<ManuallyDrop<T>> for StdMD<T> {
+    #[inline]
+    fn from(slot: ManuallyDrop<T>) -> StdMD<T> {
+        ManuallyDrop::into_std(slot)
+    }
 }
 
 // Safety: as per the invariant mentioned above.
@@ -107,17 +596,19 @@ impl<T> DerefMut for ManuallyDrop<T> {
     /// See [`::core::mem::ManuallyDrop::deref_mut()`] for more info.
     #[inline]
     fn deref_mut(&mut self) -> &mut T {
-        impl<T> Deref for ManuallyDrop<T> {
-            type Target = T;
+        unsafe { self.value.assume_init_mut() }
+    }
+}
 
-            #[inline]
-            /// See [`::core::mem::ManuallyDrop::deref()`] for more info.
-            fn deref(self: &Self) -> &T {
-                unsafe { self.value.assume_init_ref() }
-            }
-        }
+// Safety: as per the invariant mentioned above.
+#[allow(unsafe_code)]
+impl<T> Deref for ManuallyDrop<T> {
+    type Target = T;
 
-        unsafe { self.value.assume_init_mut() }
+    #[inline]
+    /// See [`::core::mem::ManuallyDrop::deref()`] for more info.
+    fn deref(self: &Self) -> &T {
+        unsafe { self.value.assume_init_ref() }
     }
 }
 
@@ -129,6 +620,43 @@ impl<T: Default> Default for ManuallyDrop<T> {
     }
 }
 
+/// Consistent with the `Hash`, `Eq` and `Ord` impls, which are forwarded to `T`.
+impl<T> ::core::borrow::Borrow<T> for ManuallyDrop<T> {
+    #[inline]
+    fn borrow(self: &Self) -> &T {
+        self
+    }
+}
+
+/// Consistent with the `Hash`, `Eq` and `Ord` impls, which are forwarded to `T`.
+impl<T> ::core::borrow::BorrowMut<T> for ManuallyDrop<T> {
+    #[inline]
+    fn borrow_mut(self: &mut Self) -> &mut T {
+        self
+    }
+}
+
+impl<T> AsRef<T> for ManuallyDrop<T> {
+    #[inline]
+    fn as_ref(self: &Self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for ManuallyDrop<T> {
+    #[inline]
+    fn as_mut(self: &mut Self) -> &mut T {
+        self
+    }
+}
+
+impl<T> From<T> for ManuallyDrop<T> {
+    #[inline]
+    fn from(value: T) -> ManuallyDrop<T> {
+        Self::new(value)
+    }
+}
+
 impl<T: Clone> Clone for ManuallyDrop<T> {
     /// See [`::core::mem::ManuallyDrop::clone()`] for more info.
     fn clone(self: &Self) -> Self {
@@ -145,6 +673,55 @@ JustDerefTM! {
         fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
     }
 
+    impl<T: fmt::Display> fmt::Display for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    /// ```rust
+    /// use ::core::fmt::Write;
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    ///
+    /// assert_eq!(format!("{:x}", MaybeDangling::new(255)), "ff");
+    /// assert_eq!(format!("{:#b} {}", ManuallyDrop::new(5), MaybeDangling::new(1.5)), "0b101 1.5");
+    ///
+    /// let mut s = MaybeDangling::new(String::new());
+    /// write!(s, "{:>4}", ManuallyDrop::new("hi")).unwrap();
+    /// assert_eq!(*s, "  hi");
+    /// ```
+    impl<T: fmt::LowerHex> fmt::LowerHex for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::UpperHex> fmt::UpperHex for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::Binary> fmt::Binary for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::Octal> fmt::Octal for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::LowerExp> fmt::LowerExp for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::UpperExp> fmt::UpperExp for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::Pointer> fmt::Pointer for ManuallyDrop<T> {
+        fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+    }
+
+    impl<T: fmt::Write> fmt::Write for ManuallyDrop<T> {
+        fn write_str(self: &mut Self, s: &str) -> fmt::Result;
+        fn write_char(self: &mut Self, c: char) -> fmt::Result;
+        fn write_fmt(self: &mut Self, args: fmt::Arguments<'_>) -> fmt::Result;
+    }
+
     impl<T: Hash> Hash for ManuallyDrop<T> {
         fn hash<__H: hash::Hasher>(self: &Self, state: &mut __H);
     }
@@ -162,6 +739,57 @@ JustDerefTM! {
     }
 
     impl<T: Eq> Eq for ManuallyDrop<T> {}
+
+    impl<T: Iterator> Iterator for ManuallyDrop<T> {
+        type Item = T::Item;
+
+        fn next(self: &mut Self) -> Option<Self::Item>;
+        fn size_hint(self: &Self) -> (usize, Option<usize>);
+        fn nth(self: &mut Self, n: usize) -> Option<Self::Item>;
+        /// This reaches `T`'s own override, if any, by moving the `T` out of the
+        /// wrapper (so that it may get dropped, even for a `ManuallyDrop`):
+        ///
+        /// ```rust
+        /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+        ///
+        /// struct Countdown(usize);
+        ///
+        /// impl Iterator for Countdown {
+        ///     type Item = usize;
+        ///
+        ///     fn next(&mut self) -> Option<usize> {
+        ///         unreachable!("the overrides below never call it");
+        ///     }
+        ///
+        ///     fn count(self) -> usize {
+        ///         self.0
+        ///     }
+        ///
+        ///     fn last(self) -> Option<usize> {
+        ///         (self.0 > 0).then(|| 0)
+        ///     }
+        /// }
+        ///
+        /// assert_eq!(MaybeDangling::new(Countdown(1000)).count(), 1000);
+        /// assert_eq!(MaybeDangling::new(Countdown(1000)).last(), Some(0));
+        /// assert_eq!(ManuallyDrop::new(Countdown(1000)).count(), 1000);
+        /// ```
+        fn count(self: Self) -> usize;
+        fn last(self: Self) -> Option<Self::Item>;
+        fn fold<Acc, F: FnMut(Acc, Self::Item) -> Acc>(self: Self, init: Acc, f: F) -> Acc;
+    }
+
+    impl<T: DoubleEndedIterator> DoubleEndedIterator for ManuallyDrop<T> {
+        fn next_back(self: &mut Self) -> Option<Self::Item>;
+        fn nth_back(self: &mut Self, n: usize) -> Option<Self::Item>;
+        fn rfold<Acc, F: FnMut(Acc, Self::Item) -> Acc>(self: Self, init: Acc, f: F) -> Acc;
+    }
+
+    impl<T: ExactSizeIterator> ExactSizeIterator for ManuallyDrop<T> {
+        fn len(self: &Self) -> usize;
+    }
+
+    impl<T: iter::FusedIterator> iter::FusedIterator for ManuallyDrop<T> {}
 }
 
 macro_rules! JustDerefTM {
@@ -189,6 +817,8 @@ macro_rules! JustDerefTM {
                 ManuallyDrop<T>
             {
                 JustDerefTM! {
+                    @impl[$($($Trait)::+)?]
+                    @for[ManuallyDrop]
                     $($inner)*
                 }
             }
@@ -203,12 +833,136 @@ macro_rules! JustDerefTM {
                 crate::MaybeDangling<T>
             {
                 JustDerefTM! {
+                    @impl[$($($Trait)::+)?]
+                    @for[MaybeDangling]
+                    $($inner)*
+                }
+            }
+        )*
+        $(
+            $(#$attr)*
+            $($($if_unsafe)?
+                unsafe
+            )?
+            impl<T: Copy $(+ $Bound)?>
+                $($($Trait)::+ for)?
+                crate::MaybeDanglingCopy<T>
+            {
+                JustDerefTM! {
+                    @impl[$($($Trait)::+)?]
+                    @for[MaybeDanglingCopy]
                     $($inner)*
                 }
             }
         )*
     );
 
+    (
+        @impl[$($Trait:ident)::*]
+        @for[$W:ident]
+    ) => ();
+
+    (
+        @impl[$($Trait:ident)::*]
+        @for[$W:ident]
+        type $Assoc:ident = $Ty:ty;
+        $($rest:tt)*
+    ) => (
+        type $Assoc = $Ty;
+
+        JustDerefTM! {
+            @impl[$($Trait)::*]
+            @for[$W]
+            $($rest)*
+        }
+    );
+
+    // By-value `self` methods: forwarded by value, through `into_inner()`,
+    // which reaches `T`'s own overrides. Much like with the by-value
+    // operators, the `T` is thus moved out of the wrapper, and may get dropped
+    // (_e.g._, `Iterator::count()` drops the iterator), even for `ManuallyDrop`.
+    (
+        @impl[$($Trait:ident)::+]
+        @for[$W:ident]
+        $(#$attr:tt)*
+        fn $fname:ident
+            $(<$($H:ident $(: $Bound:path)?),+ $(,)?>)?
+        (
+            self: Self $(,
+            $arg_name:ident : $ArgTy:ty )* $(,)?
+        ) $(-> $Ret:ty)? ;
+        $($rest:tt)*
+    ) => (
+        #[inline]
+        $(#$attr)*
+        #[doc = concat!(
+            "\nSee [`", $(stringify!($Trait), "::",)+ stringify!($fname), "()`] for more info."
+        )]
+        fn $fname
+            $(<$($H $(: $Bound)?),+>)?
+        (
+            self: Self,
+            $(
+                $arg_name : $ArgTy
+            ),*
+        ) $(-> $Ret)?
+        {
+            T::$fname(crate::$W::into_inner(self), $($arg_name),*)
+        }
+
+        JustDerefTM! {
+            @impl[$($Trait)::+]
+            @for[$W]
+            $($rest)*
+        }
+    );
+
+    (
+        @impl[$($Trait:ident)::+]
+        @for[$W:ident]
+        $(#$attr:tt)*
+        fn $fname:ident
+            $(<$($H:ident $(: $Bound:path)?),+ $(,)?>)?
+        (
+            $(
+                $arg_name:ident : $ArgTy:ty
+            ),* $(,)?
+        ) $(-> $Ret:ty)? ;
+        $($rest:tt)*
+    ) => (
+        #[inline]
+        $(#$attr)*
+        #[doc = concat!(
+            "\nSee [`", $(stringify!($Trait), "::",)+ stringify!($fname), "()`] for more info."
+        )]
+        fn $fname
+            $(<$($H $(: $Bound)?),+>)?
+        (
+            $(
+                $arg_name : $ArgTy
+            ),*
+        ) $(-> $Ret)?
+        {
+            T::$fname($($arg_name),*)
+        }
+
+        JustDerefTM! {
+            @impl[$($Trait)::+]
+            @for[$W]
+            $($rest)*
+        }
+    );
+
+    (
+        @impl[]
+        @for[$W:ident]
+        $($inner:tt)*
+    ) => (
+        JustDerefTM! {
+            $($inner)*
+        }
+    );
+
     (
         $(
             $(#$attr:tt)*
@@ -242,4 +996,4 @@ macro_rules! JustDerefTM {
         )*
     );
 }
-use JustDerefTM;
+pub(crate) use JustDerefTM;
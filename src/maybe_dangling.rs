@@ -1,5 +1,9 @@
 use crate::ManuallyDrop;
-use ::core::mem::ManuallyDrop as StdMD;
+use ::core::{
+    mem::{ManuallyDrop as StdMD, MaybeUninit as MU},
+    pin::Pin,
+    ptr::NonNull,
+};
 
 /// Like [`crate::ManuallyDrop`] but for having `drop` glue.
 /// This wrapper is 0-cost.
@@ -15,6 +19,45 @@ use ::core::mem::ManuallyDrop as StdMD;
 /// That is, it is strongly advisable to be using
 /// <code>[crate::ManuallyDrop]\<T\></code> instead!
 ///
+/// ### Conversions and transpositions
+///
+/// Both this wrapper and [`crate::ManuallyDrop`] can be transposed with the
+/// arrays, tuples, `Option`s and `Result`s they wrap (_e.g._, from a
+/// <code>[MaybeDangling]<[Option]\<T\>></code> to an
+/// <code>[Option]<[MaybeDangling]\<T\>></code>), the drop glue, if any, being
+/// carried over. Arrays and tuples are transposed in place, without ever
+/// reading their elements as bare `T`s (which would assert their validity).
+/// `Option` and `Result` ones, on the other hand, have to inspect their
+/// discriminant, so their payload does go through a bare `T`.
+///
+/// These transpositions, as well as the conversions between wrappers, are
+/// offered both as named functions (_e.g._, [`MaybeDangling::transpose_array()`],
+/// [`MaybeDangling::untranspose_tuple()`], [`ManuallyDrop::from_std()`], or
+/// [`ManuallyDrop::into_maybe_dangling()`]), and as `From` impls. The latter
+/// overlap, type-inference-wise, with the blanket `From<T>` ones, so that a
+/// partially annotated `.into()` (_e.g._, into a `MaybeDangling<_>`) is
+/// ambiguous (`E0283`): prefer the named functions.
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDangling;
+///
+/// let pair = MaybeDangling::new((String::from("a"), vec![1]));
+/// let (a, v) = MaybeDangling::transpose_tuple(pair);
+/// assert_eq!((&a[..], &v[..]), ("a", &[1][..]));
+/// let pair = MaybeDangling::untranspose_tuple((a, v));
+///
+/// let array = MaybeDangling::transpose_array(MaybeDangling::new([pair.0.clone(), "b".into()]));
+/// let back = MaybeDangling::untranspose_array(array);
+/// assert_eq!(*back, ["a", "b"]);
+///
+/// let some = MaybeDangling::transpose_option(MaybeDangling::new(Some(back)));
+/// assert!(MaybeDangling::untranspose_option(some).is_some());
+///
+/// // The `From` impls remain available, provided the target type be spelled out.
+/// let (a, v): (MaybeDangling<String>, MaybeDangling<Vec<i32>>) = pair.into();
+/// # drop((a, v));
+/// ```
+///
 /// ### Opting into unstable `#[may_dangle]` and the `dropck_eyepatch`
 ///
 /// Ironically, for this drop glue to be as smooth as it should be, the unstable
@@ -259,10 +302,15 @@ use ::core::mem::ManuallyDrop as StdMD;
 /// | has drop glue known not to involve `'dangling`<br/>_e.g._<br/>`T = Box<&'dangling str>` | ✅ | ❌ |
 /// | has drop glue (potentially) involving `'dangling`<br/>_e.g._<br/>`T = PrintOnDrop<&'dangling str>` | ❌ | ❌ |
 ///
+/// For `T : Copy` types, which belong to the first category, the
+/// [`MaybeDanglingCopy`][crate::MaybeDanglingCopy] wrapper can be used instead
+/// so as to get the ✅ on stable Rust as well.
+///
 /// [RFC-1327]: https://rust-lang.github.io/rfcs/1327-dropck-param-eyepatch.html
 /// [`drop_bounds` lint]: https://doc.rust-lang.org/1.71.0/nightly-rustc/rustc_lint/traits/static.DROP_BOUNDS.html#explanation
 /// [drop checker]: https://doc.rust-lang.org/1.71.0/nomicon/dropck.html
 /// [dropck-generics]: https://doc.rust-lang.org/1.71.0/nomicon/phantom-data.html#generic-parameters-and-drop-checking
+#[repr(transparent)]
 pub struct MaybeDangling<T> {
     value: ManuallyDrop<T>,
     #[cfg(feature = "nightly-dropck_eyepatch")]
@@ -289,8 +337,264 @@ impl<T> MaybeDangling<T> {
         // Safety: this is the defuse inherent drop glue pattern.
         unsafe { ManuallyDrop::take(&mut StdMD::new(slot).value) }
     }
+
+    /// Converts the `MaybeDangling<T>` into a [`MaybeUninit<T>`][MU] storage,
+    /// at no cost, defusing the drop glue.
+    #[inline]
+    pub fn into_maybe_uninit(slot: MaybeDangling<T>) -> MU<T> {
+        ManuallyDrop::into_maybe_uninit(Self::into_manually_drop(slot))
+    }
+
+    /// Adopts an init [`MaybeUninit<T>`][MU] storage, at no cost, arming its
+    /// drop glue.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`MaybeUninit::assume_init()`][MU::assume_init()]: `value` must be init.
+    ///
+    /// ```rust
+    /// use ::core::mem::MaybeUninit;
+    /// use ::maybe_dangling::MaybeDangling;
+    /// use ::std::rc::Rc;
+    ///
+    /// let rc = Rc::new(());
+    /// let storage = MaybeDangling::into_maybe_uninit(MaybeDangling::new(Rc::clone(&rc)));
+    /// assert_eq!(Rc::strong_count(&rc), 2); // drop glue defused…
+    /// // Safety: `storage` stems from an init value.
+    /// let mut md = unsafe { MaybeDangling::assume_init_from(storage) };
+    /// // Safety: the storage is left init.
+    /// let storage = unsafe { MaybeDangling::as_maybe_uninit_mut(&mut md) };
+    /// let prev = ::core::mem::replace(storage, MaybeUninit::new(Rc::clone(&rc)));
+    /// drop(unsafe { prev.assume_init() });
+    /// assert_eq!(Rc::strong_count(&rc), 2);
+    /// drop(md); // … and re-armed.
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    /// ```
+    #[inline]
+    pub unsafe fn assume_init_from(value: MU<T>) -> MaybeDangling<T> {
+        #![allow(unsafe_code)]
+        ManuallyDrop::into_maybe_dangling(unsafe { ManuallyDrop::assume_init_from(value) })
+    }
+
+    /// Views the value as its inner [`MaybeUninit<T>`][MU] storage.
+    #[inline]
+    pub const fn as_maybe_uninit(this: &MaybeDangling<T>) -> &MU<T> {
+        ManuallyDrop::as_maybe_uninit(&this.value)
+    }
+
+    /// Views the value as its inner [`MaybeUninit<T>`][MU] storage.
+    ///
+    /// # Safety
+    ///
+    /// The storage must be left init when the returned borrow ends, since the
+    /// value will still be dropped.
+    #[inline]
+    pub unsafe fn as_maybe_uninit_mut(this: &mut MaybeDangling<T>) -> &mut MU<T> {
+        #![allow(unsafe_code)]
+        unsafe { ManuallyDrop::as_maybe_uninit_mut(&mut this.value) }
+    }
+
+    /// Gets a raw pointer to the value, without ever materializing a `&T`.
+    ///
+    /// See [`ManuallyDrop::as_ptr()`] for more info.
+    #[inline]
+    pub const fn as_ptr(this: &MaybeDangling<T>) -> *const T {
+        ManuallyDrop::as_ptr(&this.value)
+    }
+
+    /// Gets a raw pointer to the value, without ever materializing a `&mut T`.
+    ///
+    /// See [`ManuallyDrop::as_mut_ptr()`] for more info.
+    #[inline]
+    pub fn as_mut_ptr(this: &mut MaybeDangling<T>) -> *mut T {
+        ManuallyDrop::as_mut_ptr(&mut this.value)
+    }
+
+    /// Same as [`MaybeDangling::as_mut_ptr()`], but as a [`NonNull`].
+    #[inline]
+    pub fn as_non_null(this: &mut MaybeDangling<T>) -> NonNull<T> {
+        ManuallyDrop::as_non_null(&mut this.value)
+    }
+
+    /// Casts a `*const T` to a `*const MaybeDangling<T>`, thanks to the
+    /// `#[repr(transparent)]` layout.
+    ///
+    /// See [`ManuallyDrop::from_ptr()`] for more info.
+    #[inline]
+    pub const fn from_ptr(ptr: *const T) -> *const MaybeDangling<T> {
+        ptr as _
+    }
+
+    /// Casts a `*mut T` to a `*mut MaybeDangling<T>`, thanks to the
+    /// `#[repr(transparent)]` layout.
+    ///
+    /// See [`ManuallyDrop::from_ptr()`] for more info.
+    #[inline]
+    pub const fn from_mut_ptr(ptr: *mut T) -> *mut MaybeDangling<T> {
+        ptr as _
+    }
+
+    /// Maps the value, keeping it wrapped in a `MaybeDangling`.
+    ///
+    /// Note that `f` gets the `T` by value, and thus asserts its validity.
+    #[inline]
+    pub fn map<U, F: FnOnce(T) -> U>(slot: MaybeDangling<T>, f: F) -> MaybeDangling<U> {
+        MaybeDangling::new(f(Self::into_inner(slot)))
+    }
+
+    /// Replaces the value, returning the old one, without ever materializing a
+    /// `&mut T` (contrary to `mem::replace(&mut *this, value)`).
+    ///
+    /// ```rust
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    /// use ::std::rc::Rc;
+    ///
+    /// let (a, b) = (Rc::new('a'), Rc::new('b'));
+    /// let mut x = MaybeDangling::new(Rc::clone(&a));
+    /// let mut y = MaybeDangling::new(Rc::clone(&b));
+    /// MaybeDangling::swap(&mut x, &mut y);
+    /// assert_eq!((**x, **y), ('b', 'a'));
+    ///
+    /// let prev = MaybeDangling::replace(&mut x, Rc::clone(&a));
+    /// assert!(Rc::ptr_eq(&prev, &b));
+    /// drop(prev);
+    /// assert_eq!((Rc::strong_count(&a), Rc::strong_count(&b)), (3, 1));
+    ///
+    /// let len = MaybeDangling::map(y, |rc| Rc::strong_count(&rc));
+    /// assert_eq!(*len, 3);
+    /// assert_eq!(Rc::strong_count(&a), 2); // `y`'s `Rc` got dropped by the closure.
+    ///
+    /// let mut slot = ManuallyDrop::new(Rc::clone(&b));
+    /// // Safety: the value has not been taken out already.
+    /// assert!(unsafe { ManuallyDrop::take_if(&mut slot, |rc| **rc == 'a') }.is_none());
+    /// let taken = unsafe { ManuallyDrop::take_if(&mut slot, |rc| **rc == 'b') };
+    /// assert_eq!(Rc::strong_count(&b), 2);
+    /// drop(taken); // `slot` must not be used anymore.
+    /// assert_eq!(Rc::strong_count(&b), 1);
+    /// # drop(x);
+    /// ```
+    #[inline]
+    pub fn replace(this: &mut MaybeDangling<T>, value: T) -> T {
+        #![allow(unsafe_code)]
+        // Safety: the value of a `MaybeDangling` is never taken out nor
+        // dropped but in its own `Drop` impl.
+        unsafe { ManuallyDrop::replace(&mut this.value, value) }
+    }
+
+    /// Swaps the values, without ever materializing a `&mut T` (contrary to
+    /// `mem::swap(&mut *this, &mut *other)`).
+    #[inline]
+    pub fn swap(this: &mut MaybeDangling<T>, other: &mut MaybeDangling<T>) {
+        ::core::mem::swap(&mut this.value, &mut other.value)
+    }
+
+    /// Defuses the drop glue: converts into a [`ManuallyDrop`], at no cost.
+    ///
+    /// See [`ManuallyDrop::into_maybe_dangling()`] for the converse.
+    ///
+    /// ```rust
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    /// use ::std::rc::Rc;
+    ///
+    /// let rc = Rc::new(());
+    /// let defused = MaybeDangling::into_manually_drop(MaybeDangling::new(Rc::clone(&rc)));
+    /// assert_eq!(Rc::strong_count(&rc), 2);
+    ///
+    /// let rearmed = ManuallyDrop::into_maybe_dangling(defused);
+    /// drop(rearmed);
+    /// assert_eq!(Rc::strong_count(&rc), 1);
+    ///
+    /// let mut slot = ManuallyDrop::new(Rc::clone(&rc));
+    /// // Safety: the `Rc` is fine to drop.
+    /// let view = unsafe { ManuallyDrop::as_maybe_dangling_mut(&mut slot) };
+    /// *view = MaybeDangling::new(Rc::clone(&rc)); // drops the previous `Rc`.
+    /// assert_eq!(Rc::strong_count(&rc), 2);
+    /// # unsafe { ManuallyDrop::drop(&mut slot) }
+    /// ```
+    #[inline]
+    pub fn into_manually_drop(slot: MaybeDangling<T>) -> ManuallyDrop<T> {
+        #![allow(unsafe_code)]
+        // Safety: `#[repr(transparent)]` wrapper around a `ManuallyDrop<T>`,
+        // and the inherent drop glue is defused.
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(slot)) }
+    }
+
+    /// Views the `MaybeDangling<T>` as the inner [`ManuallyDrop`].
+    #[inline]
+    pub const fn as_manually_drop(this: &MaybeDangling<T>) -> &ManuallyDrop<T> {
+        &this.value
+    }
+
+    /// Views the `MaybeDangling<T>` as the inner [`ManuallyDrop`].
+    ///
+    /// A value written through it will still be dropped by the
+    /// `MaybeDangling`, whereas a value taken out of it won't.
+    #[inline]
+    pub fn as_manually_drop_mut(this: &mut MaybeDangling<T>) -> &mut ManuallyDrop<T> {
+        &mut this.value
+    }
+
+    /// Pin projection: from a pinned `MaybeDangling<T>` to a pinned `T`.
+    ///
+    /// Pinning is _structural_ for `MaybeDangling`:
+    ///
+    ///   - its `Drop` impl drops the `T` in place, and never moves it out;
+    ///   - it is `Unpin` if and only if `T` is;
+    ///   - it offers no API to move the `T` out of a pinned `MaybeDangling<T>`:
+    ///     those taking a `&mut MaybeDangling<T>` (_e.g._, [`DerefMut`][
+    ///     ::core::ops::DerefMut], or [`Self::as_manually_drop_mut()`]) require
+    ///     `T : Unpin` when pinned.
+    #[inline]
+    pub fn as_pin_ref(self: Pin<&Self>) -> Pin<&T> {
+        #![allow(unsafe_code)]
+        // Safety: pinning is structural, see above.
+        unsafe { self.map_unchecked(|this| &**this) }
+    }
+
+    /// Pin projection: from a pinned `MaybeDangling<T>` to a pinned `T`.
+    ///
+    /// See [`Self::as_pin_ref()`] for more info.
+    #[inline]
+    pub fn as_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        #![allow(unsafe_code)]
+        // Safety: pinning is structural, see `as_pin_ref()`.
+        unsafe { self.map_unchecked_mut(|this| &mut **this) }
+    }
 }
 
+/// Pins the given local variables on the stack, wrapped in a [`MaybeDangling`].
+///
+/// Each `$var: T` gets shadowed by a <code>[Pin]<&mut [MaybeDangling]\<T\>></code>,
+/// which can be [projected][MaybeDangling::as_pin_mut()] to a
+/// <code>[Pin]<&mut T></code>.
+///
+/// ```rust
+/// use ::core::{marker::PhantomPinned, pin::Pin};
+/// use ::maybe_dangling::{maybe_dangling_pin, MaybeDangling};
+///
+/// let a = PhantomPinned;
+/// let b = PhantomPinned;
+/// maybe_dangling_pin!(a, b);
+/// let _: Pin<&mut MaybeDangling<PhantomPinned>> = a;
+/// let _: Pin<&mut PhantomPinned> = b.as_pin_mut();
+/// ```
+///
+/// [Pin]: ::core::pin::Pin
+#[macro_export]
+macro_rules! maybe_dangling_pin {(
+    $($var:ident),* $(,)?
+) => (
+    $(
+        let mut $var = $crate::MaybeDangling::new($var);
+        #[allow(unused_mut)]
+        // Safety: the original binding is shadowed, so it can no longer be
+        // moved, and `MaybeDangling` drops its contents in place.
+        let mut $var = unsafe {
+            ::core::pin::Pin::new_unchecked(&mut $var)
+        };
+    )*
+)}
+
 // The main difference with `ManuallyDrop`: automatic drop glue!
 crate::match_cfg! {
     feature = "nightly-dropck_eyepatch" => {
@@ -319,16 +623,16 @@ crate::match_cfg! {
 impl<T> ::core::ops::DerefMut for MaybeDangling<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut T {
-        impl<T> ::core::ops::Deref for MaybeDangling<T> {
-            type Target = T;
+        let Self { value, .. } = self;
+        value
+    }
+}
 
-            #[inline]
-            fn deref(self: &Self) -> &T {
-                let Self { value, .. } = self;
-                value
-            }
-        }
+impl<T> ::core::ops::Deref for MaybeDangling<T> {
+    type Target = T;
 
+    #[inline]
+    fn deref(self: &Self) -> &T {
         let Self { value, .. } = self;
         value
     }
@@ -350,3 +654,63 @@ impl<T: Clone> Clone for MaybeDangling<T> {
         T::clone_from(self, source)
     }
 }
+
+/// Consistent with the `Hash`, `Eq` and `Ord` impls, which are forwarded to `T`.
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDangling;
+/// use ::std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert(MaybeDangling::new(42_u32), "the answer");
+/// assert_eq!(map.get(&42), Some(&"the answer"));
+/// ```
+impl<T> ::core::borrow::Borrow<T> for MaybeDangling<T> {
+    #[inline]
+    fn borrow(self: &Self) -> &T {
+        self
+    }
+}
+
+/// Consistent with the `Hash`, `Eq` and `Ord` impls, which are forwarded to `T`.
+impl<T> ::core::borrow::BorrowMut<T> for MaybeDangling<T> {
+    #[inline]
+    fn borrow_mut(self: &mut Self) -> &mut T {
+        self
+    }
+}
+
+impl<T> AsRef<T> for MaybeDangling<T> {
+    #[inline]
+    fn as_ref(self: &Self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for MaybeDangling<T> {
+    #[inline]
+    fn as_mut(self: &mut Self) -> &mut T {
+        self
+    }
+}
+
+impl<T> From<T> for MaybeDangling<T> {
+    #[inline]
+    fn from(value: T) -> MaybeDangling<T> {
+        Self::new(value)
+    }
+}
+
+impl<T> From<ManuallyDrop<T>> for MaybeDangling<T> {
+    #[inline]
+    fn from(slot: ManuallyDrop<T>) -> MaybeDangling<T> {
+        ManuallyDrop::into_maybe_dangling(slot)
+    }
+}
+
+impl<T> From<MaybeDangling<T>> for ManuallyDrop<T> {
+    #[inline]
+    fn from(slot: MaybeDangling<T>) -> ManuallyDrop<T> {
+        MaybeDangling::into_manually_drop(slot)
+    }
+}
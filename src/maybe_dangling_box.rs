@@ -0,0 +1,176 @@
+use crate::MaybeDanglingPtr;
+use ::alloc::boxed::Box;
+use ::core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::ManuallyDrop as StdMD,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+/// An aliasable <code>[Box]\<T\></code>: an owning pointer to a heap
+/// allocation, which does not assert uniqueness (nor `dereferenceable`-ity) of
+/// its pointee.
+/// This wrapper is 0-cost, and keeps the null-pointer niche.
+///
+/// A [`Box`] is, like a <code>[&mut T][reference]</code>, assumed to be the
+/// unique pointer to its pointee, so that moving it around invalidates any
+/// other pointer to the allocation. This makes it unsuitable as the owner in
+/// self-referential or intrusive data structures, whereas a
+/// [`MaybeDanglingBox`] is fine:
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDanglingBox;
+///
+/// let owner = MaybeDanglingBox::from_box(Box::new(42));
+/// let ptr: *const i32 = MaybeDanglingBox::as_ptr(&owner);
+/// let mut owner = ::core::convert::identity(owner); // move it around.
+/// assert_eq!(unsafe { *ptr }, 42); // OK, `ptr` is still usable.
+/// *owner += 1;
+/// assert_eq!(*owner, 43);
+/// ```
+///
+/// It [`Deref`]s to `T`, and never hands out the [`Box`] itself, except
+/// through [`MaybeDanglingBox::into_box()`], which asserts uniqueness of the
+/// pointee again.
+#[repr(transparent)]
+pub struct MaybeDanglingBox<T: ?Sized> {
+    /// # SAFETY INVARIANT: stems from a `Box<T>`, which we own.
+    ptr: NonNull<T>,
+    _owns: PhantomData<T>,
+}
+
+impl<T: ?Sized> MaybeDanglingBox<T> {
+    /// Takes ownership of a [`Box`]'s allocation.
+    #[inline]
+    pub fn from_box(b: Box<T>) -> MaybeDanglingBox<T> {
+        #![allow(unsafe_code)]
+        Self {
+            // Safety: a `Box` is never null.
+            ptr: unsafe { NonNull::new_unchecked(Box::into_raw(b)) },
+            _owns: PhantomData,
+        }
+    }
+
+    /// Gives the allocation back to a [`Box`].
+    ///
+    /// This asserts uniqueness of the pointee again, thereby invalidating any
+    /// other pointer to it.
+    #[inline]
+    pub fn into_box(this: MaybeDanglingBox<T>) -> Box<T> {
+        #![allow(unsafe_code)]
+        // Safety: as per the safety invariant, and the drop glue is defused.
+        unsafe { Box::from_raw(StdMD::new(this).ptr.as_ptr()) }
+    }
+
+    /// Gets a raw pointer to the pointee.
+    ///
+    /// This pointer remains usable even after the `MaybeDanglingBox` is moved
+    /// around, since doing so does not assert uniqueness of the pointee.
+    #[inline]
+    pub fn as_ptr(this: &MaybeDanglingBox<T>) -> *const T {
+        this.ptr.as_ptr()
+    }
+
+    /// Gets a raw pointer to the pointee, which may be written through.
+    ///
+    /// See [`MaybeDanglingBox::as_ptr()`] for more info.
+    #[inline]
+    pub fn as_mut_ptr(this: &mut MaybeDanglingBox<T>) -> *mut T {
+        this.ptr.as_ptr()
+    }
+}
+
+// The drop glue of `Box<T>`, much like for `MaybeDangling`.
+crate::match_cfg! {
+    feature = "nightly-dropck_eyepatch" => {
+        #[allow(unsafe_code)]
+        unsafe impl<#[may_dangle] T: ?Sized> Drop for MaybeDanglingBox<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    drop(Box::from_raw(self.ptr.as_ptr()))
+                }
+            }
+        }
+    },
+
+    _ => {
+        impl<T: ?Sized> Drop for MaybeDanglingBox<T> {
+            fn drop(&mut self) {
+                #![allow(unsafe_code)]
+                unsafe {
+                    drop(Box::from_raw(self.ptr.as_ptr()))
+                }
+            }
+        }
+    },
+}
+
+// Safety: as per the safety invariant of the `ptr` field.
+#[allow(unsafe_code)]
+impl<T: ?Sized> Deref for MaybeDanglingBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &Self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+// Safety: as per the safety invariant of the `ptr` field.
+#[allow(unsafe_code)]
+impl<T: ?Sized> DerefMut for MaybeDanglingBox<T> {
+    #[inline]
+    fn deref_mut(self: &mut Self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+// Safety: same as for `Box<T>`.
+#[allow(unsafe_code)]
+unsafe impl<T: ?Sized + Send> Send for MaybeDanglingBox<T> {}
+
+// Safety: same as for `Box<T>`.
+#[allow(unsafe_code)]
+unsafe impl<T: ?Sized + Sync> Sync for MaybeDanglingBox<T> {}
+
+impl<T: Clone> Clone for MaybeDanglingBox<T> {
+    #[inline]
+    fn clone(self: &Self) -> Self {
+        Self::from_box(Box::new(T::clone(self)))
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for MaybeDanglingBox<T> {
+    #[inline]
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt(self, f)
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for MaybeDanglingBox<T> {
+    #[inline]
+    fn from(b: Box<T>) -> MaybeDanglingBox<T> {
+        Self::from_box(b)
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`
+// stemming from an owned `Box<T>`, and the drop glue of the source is defused.
+#[allow(unsafe_code)]
+impl<T: ?Sized> From<MaybeDanglingPtr<Box<T>>> for MaybeDanglingBox<T> {
+    #[inline]
+    fn from(ptr: MaybeDanglingPtr<Box<T>>) -> MaybeDanglingBox<T> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(ptr)) }
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`
+// stemming from an owned `Box<T>`, and the drop glue of the source is defused.
+#[allow(unsafe_code)]
+impl<T: ?Sized> From<MaybeDanglingBox<T>> for MaybeDanglingPtr<Box<T>> {
+    #[inline]
+    fn from(b: MaybeDanglingBox<T>) -> MaybeDanglingPtr<Box<T>> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(b)) }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::{
+    mem::ManuallyDrop as StdMD,
+    ops::{Deref, DerefMut},
+};
+
+/// Like [`MaybeDangling`], but for `T : Copy` payloads, thereby not needing
+/// any `Drop` impl whatsoever.
+/// This wrapper is 0-cost.
+///
+/// Without the `nightly-dropck_eyepatch` feature, even a <code>[MaybeDangling]\<T\></code>
+/// with no drop glue, such as `T = &'dangling str`, is not allowed to go out
+/// of scope once its `'dangling` lifetime has expired, because of its
+/// (`#[may_dangle]`-less) `Drop` impl.
+///
+/// Since a `T : Copy` cannot have drop glue, a
+/// <code>[MaybeDanglingCopy]\<T\></code> can skip the `Drop` impl altogether,
+/// and the drop checker then lets it go out of scope, even on stable Rust:
+///
+/// ```
+/// use ::maybe_dangling::MaybeDanglingCopy;
+///
+/// fn main() {
+///     let s: String = "I will dangle".into();
+///     let v = MaybeDanglingCopy::new(&s);
+///     drop(s); // <- makes `&s` dangle
+/// } // <- `v` goes out of scope here, despite containing a `&'dangling s` reference!
+/// ```
+///
+/// It can be converted back and forth from a <code>[MaybeDangling]\<T\></code>,
+/// at no cost, and forwards the same traits (`Debug` and the other `fmt` ones,
+/// `Hash`, the comparison ones, and the `Iterator` family), so that it can be
+/// swapped in for it:
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDanglingCopy;
+/// use ::std::collections::HashSet;
+///
+/// let set: HashSet<_> = ["a", "b"].into_iter().map(MaybeDanglingCopy::new).collect();
+/// assert!(set.contains(&MaybeDanglingCopy::new("a")));
+/// assert!(MaybeDanglingCopy::new(1) < MaybeDanglingCopy::new(2));
+/// assert_eq!(format!("{:?} {:x}", MaybeDanglingCopy::new('c'), MaybeDanglingCopy::new(255)), "'c' ff");
+/// ```
+#[repr(transparent)]
+pub struct MaybeDanglingCopy<T: Copy> {
+    value: ManuallyDrop<T>,
+}
+
+impl<T: Copy> MaybeDanglingCopy<T> {
+    pub const fn new(value: T) -> MaybeDanglingCopy<T> {
+        Self {
+            value: ManuallyDrop::new(value),
+        }
+    }
+
+    /// Extracts the value from the `MaybeDanglingCopy` container.
+    ///
+    /// See [`::core::mem::ManuallyDrop::into_inner()`] for more info.
+    #[inline]
+    pub const fn into_inner(slot: MaybeDanglingCopy<T>) -> T {
+        ManuallyDrop::into_inner(slot.value)
+    }
+}
+
+impl<T: Copy> DerefMut for MaybeDanglingCopy<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Copy> Deref for MaybeDanglingCopy<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(self: &Self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Copy> Copy for MaybeDanglingCopy<T> {}
+
+impl<T: Copy> Clone for MaybeDanglingCopy<T> {
+    #[inline]
+    fn clone(self: &Self) -> Self {
+        *self
+    }
+}
+
+impl<T: Copy + Default> Default for MaybeDanglingCopy<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `ManuallyDrop<T>`.
+#[allow(unsafe_code)]
+impl<T: Copy> From<MaybeDangling<T>> for MaybeDanglingCopy<T> {
+    #[inline]
+    fn from(slot: MaybeDangling<T>) -> MaybeDanglingCopy<T> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(slot)) }
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `ManuallyDrop<T>`.
+#[allow(unsafe_code)]
+impl<T: Copy> From<MaybeDanglingCopy<T>> for MaybeDangling<T> {
+    #[inline]
+    fn from(slot: MaybeDanglingCopy<T>) -> MaybeDangling<T> {
+        unsafe { ::core::mem::transmute_copy(&slot) }
+    }
+}
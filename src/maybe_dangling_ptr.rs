@@ -0,0 +1,261 @@
+use crate::MaybeDangling;
+use ::core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::ManuallyDrop as StdMD,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+};
+
+/// Like <code>[MaybeDangling]\<P\></code>, but for pointer-like payloads, for
+/// which the null-pointer niche is kept.
+/// This wrapper is 0-cost.
+///
+/// Since [`MaybeDangling`] is implemented using [`::core::mem::MaybeUninit`],
+/// it disables niches, which means that an
+/// <code>[Option]<[MaybeDangling]<[&T][reference]>></code> currently takes
+/// _two_ pointers worth of space rather than _one_ (which is not to be relied
+/// upon either, see the docs of [`crate::ManuallyDrop`]).
+///
+/// By storing a [`NonNull`] pointer instead, a
+/// <code>[MaybeDanglingPtr]\<P\></code> is still stripped of the
+/// aliasing/`dereferenceable`-ity properties of `P`, but keeps its niche:
+///
+/// ```rust
+/// use ::core::mem::size_of;
+/// use ::maybe_dangling::MaybeDanglingPtr;
+///
+/// assert_eq!(size_of::<Option<MaybeDanglingPtr<&u8>>>(), size_of::<&u8>());
+/// assert_eq!(size_of::<Option<MaybeDanglingPtr<&mut str>>>(), size_of::<&str>());
+/// ```
+///
+/// `P` can be any of the [`PointerLike`] types:
+///
+///   - <code>[&\'a T][reference]</code>,
+///   - <code>[&\'a mut T][reference]</code>,
+///   - <code>[Box]\<T\></code> (requires the `alloc` Cargo feature),
+///
+/// with `T : ?Sized`. The value can be converted back and forth from the
+/// <code>[MaybeDangling]\<P\></code> counterpart, at no cost.
+///
+/// Other than that, it behaves like <code>[MaybeDangling]\<P\></code>: it
+/// [`Deref`]s to `P`, and it has the drop glue of `P` (which only matters for
+/// <code>[Box]\<T\></code>).
+///
+/// For the pointers which own or exclusively borrow their pointee, the
+/// dedicated [`AliasableMut`][crate::AliasableMut] and `MaybeDanglingBox`
+/// types [`Deref`] to the pointee instead.
+///
+/// ### Variance
+///
+/// Like <code>[MaybeDangling]\<P\></code>, a
+/// <code>[MaybeDanglingPtr]\<P\></code> is _covariant_ in `P`, so that it can
+/// be used as a drop-in replacement for it:
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDanglingPtr;
+///
+/// fn shorten<'s, 'l : 's>(p: MaybeDanglingPtr<&'l str>) -> MaybeDanglingPtr<&'s str> {
+///     p
+/// }
+///
+/// fn shorten_mut<'s, 'l : 's>(p: MaybeDanglingPtr<&'l mut i32>) -> MaybeDanglingPtr<&'s mut i32> {
+///     p
+/// }
+/// ```
+///
+/// This is what the second type parameter, `T`, is for: it is always
+/// `P::Pointee`, and is not meant to be specified explicitly, but it lets the
+/// pointer be stored as a <code>[NonNull]\<T\></code> without going through
+/// the (invariant) `P::Pointee` projection.
+///
+/// [Box]: https://doc.rust-lang.org/stable/alloc/boxed/struct.Box.html
+#[repr(transparent)]
+pub struct MaybeDanglingPtr<P, T: ?Sized = <P as PointerLike>::Pointee>
+where
+    P: PointerLike<Pointee = T>,
+{
+    /// # SAFETY INVARIANT: these are the bits of a `P`.
+    ptr: NonNull<T>,
+    _owns: PhantomData<P>,
+}
+
+/// The pointer types which a [`MaybeDanglingPtr`] may wrap.
+///
+/// This trait is sealed: it is implemented for <code>[&T][reference]</code>,
+/// <code>[&mut T][reference]</code>, and, with the `alloc` Cargo feature,
+/// <code>[Box]\<T\></code>.
+///
+/// # Safety
+///
+/// `Self` must have the same layout as <code>[NonNull]\<Self::Pointee\></code>.
+///
+/// [Box]: https://doc.rust-lang.org/stable/alloc/boxed/struct.Box.html
+#[allow(unsafe_code)]
+pub unsafe trait PointerLike: Sized + sealed::Sealed {
+    /// The type being pointed to.
+    type Pointee: ?Sized;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+// Safety: references and raw pointers have the same layout.
+#[allow(unsafe_code)]
+unsafe impl<T: ?Sized> PointerLike for &T {
+    type Pointee = T;
+}
+impl<T: ?Sized> sealed::Sealed for &T {}
+
+// Safety: references and raw pointers have the same layout.
+#[allow(unsafe_code)]
+unsafe impl<T: ?Sized> PointerLike for &mut T {
+    type Pointee = T;
+}
+impl<T: ?Sized> sealed::Sealed for &mut T {}
+
+// Safety: a `Box<T>` is represented as a `*mut T`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "alloc")))]
+#[allow(unsafe_code)]
+unsafe impl<T: ?Sized> PointerLike for ::alloc::boxed::Box<T> {
+    type Pointee = T;
+}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> sealed::Sealed for ::alloc::boxed::Box<T> {}
+
+// Safety: as per the `PointerLike` layout guarantee, and the safety invariant
+// of the `ptr` field.
+#[allow(unsafe_code)]
+impl<P: PointerLike> MaybeDanglingPtr<P> {
+    /// Wrap a pointer so as to strip it of its aliasing/`dereferenceable`-ity
+    /// properties.
+    #[inline]
+    pub fn new(ptr: P) -> MaybeDanglingPtr<P> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(ptr)) }
+    }
+
+    /// Extracts the pointer from the `MaybeDanglingPtr` container.
+    ///
+    /// See [`::core::mem::ManuallyDrop::into_inner()`] for more info.
+    #[inline]
+    pub fn into_inner(slot: MaybeDanglingPtr<P>) -> P {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(slot)) }
+    }
+
+    /// Gets a raw pointer to the pointee, without ever going through `P`.
+    ///
+    /// This pointer remains usable even after the `MaybeDanglingPtr` is moved
+    /// around, since doing so does not assert uniqueness of the pointee.
+    #[inline]
+    pub fn as_ptr(this: &MaybeDanglingPtr<P>) -> *const P::Pointee {
+        this.ptr.as_ptr()
+    }
+
+    /// Gets a raw pointer to the pointee, without ever going through `P`.
+    ///
+    /// Writing through it is only sound if `P` allows it (_e.g._,
+    /// <code>[&mut T][reference]</code> or <code>[Box]\<T\></code>).
+    ///
+    /// [Box]: https://doc.rust-lang.org/stable/alloc/boxed/struct.Box.html
+    #[inline]
+    pub fn as_mut_ptr(this: &mut MaybeDanglingPtr<P>) -> *mut P::Pointee {
+        this.ptr.as_ptr()
+    }
+}
+
+// The drop glue of `P`, much like for `MaybeDangling`.
+crate::match_cfg! {
+    feature = "nightly-dropck_eyepatch" => {
+        #[allow(unsafe_code)]
+        unsafe impl<#[may_dangle] P, #[may_dangle] T: ?Sized> Drop for MaybeDanglingPtr<P, T>
+        where
+            P: PointerLike<Pointee = T>,
+        {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(&mut self.ptr as *mut _ as *mut P)
+                }
+            }
+        }
+    },
+
+    _ => {
+        impl<P, T: ?Sized> Drop for MaybeDanglingPtr<P, T>
+        where
+            P: PointerLike<Pointee = T>,
+        {
+            fn drop(&mut self) {
+                #![allow(unsafe_code)]
+                unsafe {
+                    ptr::drop_in_place(&mut self.ptr as *mut _ as *mut P)
+                }
+            }
+        }
+    },
+}
+
+// Safety: as per the `PointerLike` layout guarantee, and the safety invariant
+// of the `ptr` field.
+#[allow(unsafe_code)]
+impl<P: PointerLike> DerefMut for MaybeDanglingPtr<P> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut P {
+        unsafe { &mut *(&mut self.ptr as *mut _ as *mut P) }
+    }
+}
+
+// Safety: as per the `PointerLike` layout guarantee, and the safety invariant
+// of the `ptr` field.
+#[allow(unsafe_code)]
+impl<P: PointerLike> Deref for MaybeDanglingPtr<P> {
+    type Target = P;
+
+    #[inline]
+    fn deref(self: &Self) -> &P {
+        unsafe { &*(&self.ptr as *const _ as *const P) }
+    }
+}
+
+// Safety: we own a `P`.
+#[allow(unsafe_code)]
+unsafe impl<P: PointerLike + Send> Send for MaybeDanglingPtr<P> {}
+
+// Safety: we own a `P`.
+#[allow(unsafe_code)]
+unsafe impl<P: PointerLike + Sync> Sync for MaybeDanglingPtr<P> {}
+
+impl<P: PointerLike + Clone> Clone for MaybeDanglingPtr<P> {
+    #[inline]
+    fn clone(self: &Self) -> Self {
+        Self::new(P::clone(self))
+    }
+}
+
+impl<P: PointerLike + Debug> Debug for MaybeDanglingPtr<P> {
+    #[inline]
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::fmt(self, f)
+    }
+}
+
+// Safety: `MaybeDangling<P>` is a `#[repr(transparent)]` wrapper around `P`,
+// which has the same layout as `MaybeDanglingPtr<P>`.
+#[allow(unsafe_code)]
+impl<P: PointerLike> From<MaybeDangling<P>> for MaybeDanglingPtr<P> {
+    #[inline]
+    fn from(slot: MaybeDangling<P>) -> MaybeDanglingPtr<P> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(slot)) }
+    }
+}
+
+// Safety: `MaybeDangling<P>` is a `#[repr(transparent)]` wrapper around `P`,
+// which has the same layout as `MaybeDanglingPtr<P>`.
+#[allow(unsafe_code)]
+impl<P: PointerLike> From<MaybeDanglingPtr<P>> for MaybeDangling<P> {
+    #[inline]
+    fn from(slot: MaybeDanglingPtr<P>) -> MaybeDangling<P> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(slot)) }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{MaybeDangling, MaybeDanglingPtr};
+use ::core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::ManuallyDrop as StdMD,
+    ptr::NonNull,
+};
+
+/// A <code>[&\'a T][reference]</code> which is not asserted to be
+/// `dereferenceable`, and which thus may be held onto past the validity of
+/// its referent (_e.g._, in some callback registration).
+/// This wrapper is 0-cost, and keeps the null-pointer niche.
+///
+/// Contrary to <code>[MaybeDangling]<[&\'a T][reference]></code> or
+/// <code>[MaybeDanglingPtr]<[&\'a T][reference]></code>, which [`Deref`][
+/// ::core::ops::Deref] to the reference, and thus make it too easy to
+/// accidentally access a dangling referent, accessing the referent of a
+/// [`MaybeDanglingRef`] requires an explicit `unsafe` call to
+/// [`.get()`][Self::get()].
+///
+/// It is also [`Copy`], like the reference it stems from.
+///
+/// ```rust
+/// use ::maybe_dangling::MaybeDanglingRef;
+///
+/// let s = String::from("hello");
+/// let handle = MaybeDanglingRef::new(&s[..]);
+/// let copy = handle;
+/// // Safety: `s` is still alive.
+/// assert_eq!(unsafe { handle.get() }, "hello");
+/// assert_eq!(MaybeDanglingRef::as_ptr(copy) as *const u8, s.as_ptr());
+/// ```
+#[repr(transparent)]
+pub struct MaybeDanglingRef<'a, T: ?Sized> {
+    ptr: NonNull<T>,
+    _borrows: PhantomData<&'a T>,
+}
+
+impl<'a, T: ?Sized> MaybeDanglingRef<'a, T> {
+    /// Strips a <code>[&\'a T][reference]</code> of its
+    /// `dereferenceable`-ity.
+    #[inline]
+    pub const fn new(r: &'a T) -> MaybeDanglingRef<'a, T> {
+        #![allow(unsafe_code)]
+        Self {
+            // Safety: a reference is never null.
+            ptr: unsafe { NonNull::new_unchecked(r as *const T as *mut T) },
+            _borrows: PhantomData,
+        }
+    }
+
+    /// Gets the reference back.
+    ///
+    /// # Safety
+    ///
+    /// The referent must not have been invalidated (_e.g._, freed, or mutated
+    /// through some other pointer), and must not be so for the lifetime of
+    /// the returned reference.
+    #[inline]
+    #[allow(unsafe_code)]
+    pub unsafe fn get(self: &Self) -> &'a T {
+        unsafe { &*self.ptr.as_ptr() }
+    }
+
+    /// Gets a raw pointer to the referent, without dereferencing it.
+    #[inline]
+    pub const fn as_ptr(this: MaybeDanglingRef<'a, T>) -> *const T {
+        this.ptr.as_ptr()
+    }
+}
+
+impl<'a, T: ?Sized> Copy for MaybeDanglingRef<'a, T> {}
+
+impl<'a, T: ?Sized> Clone for MaybeDanglingRef<'a, T> {
+    #[inline]
+    fn clone(self: &Self) -> Self {
+        *self
+    }
+}
+
+// Safety: same as for `&'a T`.
+#[allow(unsafe_code)]
+unsafe impl<'a, T: ?Sized + Sync> Send for MaybeDanglingRef<'a, T> {}
+
+// Safety: same as for `&'a T`.
+#[allow(unsafe_code)]
+unsafe impl<'a, T: ?Sized + Sync> Sync for MaybeDanglingRef<'a, T> {}
+
+/// Only shows the address, since the referent may be dangling.
+impl<'a, T: ?Sized> Debug for MaybeDanglingRef<'a, T> {
+    fn fmt(self: &Self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MaybeDanglingRef")
+            .field(&self.ptr.as_ptr())
+            .finish()
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a T> for MaybeDanglingRef<'a, T> {
+    #[inline]
+    fn from(r: &'a T) -> MaybeDanglingRef<'a, T> {
+        Self::new(r)
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> From<MaybeDanglingPtr<&'a T>> for MaybeDanglingRef<'a, T> {
+    #[inline]
+    fn from(ptr: MaybeDanglingPtr<&'a T>) -> MaybeDanglingRef<'a, T> {
+        unsafe { ::core::mem::transmute_copy(&StdMD::new(ptr)) }
+    }
+}
+
+impl<'a, T: ?Sized> From<MaybeDangling<&'a T>> for MaybeDanglingRef<'a, T> {
+    #[inline]
+    fn from(r: MaybeDangling<&'a T>) -> MaybeDanglingRef<'a, T> {
+        MaybeDanglingPtr::from(r).into()
+    }
+}
+
+// Safety: both are `#[repr(transparent)]` wrappers around a `NonNull<T>`.
+#[allow(unsafe_code)]
+impl<'a, T: ?Sized> From<MaybeDanglingRef<'a, T>> for MaybeDanglingPtr<&'a T> {
+    #[inline]
+    fn from(r: MaybeDanglingRef<'a, T>) -> MaybeDanglingPtr<&'a T> {
+        unsafe { ::core::mem::transmute_copy(&r) }
+    }
+}
@@ -0,0 +1,183 @@
+//! Forwarding of the [`::core::ops`] operators: see the "Operators" section
+//! of the docs of [`ManuallyDrop`].
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::ops::*;
+
+macro_rules! OpsTM {
+    (
+        $(
+            for $W:ident;
+        )*
+        binary $binary:tt
+        shift $shift:tt
+        unary $unary:tt
+    ) => (
+        $(
+            OpsTM! { @binary $W $binary }
+            OpsTM! { @shift $W $shift }
+            OpsTM! { @unary $W $unary }
+        )*
+    );
+
+    (
+        @binary $W:ident [$(
+            $Op:ident::$op:ident, $OpAssign:ident::$op_assign:ident;
+        )*]
+    ) => (
+        $(
+            impl<T: $Op> $Op for $W<T> {
+                type Output = $W<T::Output>;
+
+                #[inline]
+                fn $op(self: $W<T>, rhs: $W<T>) -> $W<T::Output> {
+                    $W::new(T::$op($W::into_inner(self), $W::into_inner(rhs)))
+                }
+            }
+
+            impl<'r, T> $Op<&'r $W<T>> for $W<T>
+            where
+                T: $Op<&'r T>,
+            {
+                type Output = $W<<T as $Op<&'r T>>::Output>;
+
+                #[inline]
+                fn $op(self: $W<T>, rhs: &'r $W<T>) -> Self::Output {
+                    $W::new(T::$op($W::into_inner(self), &**rhs))
+                }
+            }
+
+            impl<'l, T> $Op<$W<T>> for &'l $W<T>
+            where
+                &'l T: $Op<T>,
+            {
+                type Output = $W<<&'l T as $Op<T>>::Output>;
+
+                #[inline]
+                fn $op(self: &'l $W<T>, rhs: $W<T>) -> Self::Output {
+                    $W::new(<&'l T>::$op(&**self, $W::into_inner(rhs)))
+                }
+            }
+
+            impl<'l, 'r, T> $Op<&'r $W<T>> for &'l $W<T>
+            where
+                &'l T: $Op<&'r T>,
+            {
+                type Output = $W<<&'l T as $Op<&'r T>>::Output>;
+
+                #[inline]
+                fn $op(self: &'l $W<T>, rhs: &'r $W<T>) -> Self::Output {
+                    $W::new(<&'l T>::$op(&**self, &**rhs))
+                }
+            }
+
+            impl<T: $OpAssign> $OpAssign for $W<T> {
+                #[inline]
+                fn $op_assign(self: &mut $W<T>, rhs: $W<T>) {
+                    T::$op_assign(self, $W::into_inner(rhs))
+                }
+            }
+
+            impl<'r, T> $OpAssign<&'r $W<T>> for $W<T>
+            where
+                T: $OpAssign<&'r T>,
+            {
+                #[inline]
+                fn $op_assign(self: &mut $W<T>, rhs: &'r $W<T>) {
+                    T::$op_assign(self, &**rhs)
+                }
+            }
+        )*
+    );
+
+    (
+        @shift $W:ident [$(
+            $Sh:ident::$sh:ident, $ShAssign:ident::$sh_assign:ident;
+        )*]
+    ) => (
+        $(
+            impl<T: $Sh<Rhs>, Rhs> $Sh<Rhs> for $W<T> {
+                type Output = $W<T::Output>;
+
+                #[inline]
+                fn $sh(self: $W<T>, rhs: Rhs) -> $W<T::Output> {
+                    $W::new(T::$sh($W::into_inner(self), rhs))
+                }
+            }
+
+            impl<'l, T, Rhs> $Sh<Rhs> for &'l $W<T>
+            where
+                &'l T: $Sh<Rhs>,
+            {
+                type Output = $W<<&'l T as $Sh<Rhs>>::Output>;
+
+                #[inline]
+                fn $sh(self: &'l $W<T>, rhs: Rhs) -> Self::Output {
+                    $W::new(<&'l T>::$sh(&**self, rhs))
+                }
+            }
+
+            impl<T: $ShAssign<Rhs>, Rhs> $ShAssign<Rhs> for $W<T> {
+                #[inline]
+                fn $sh_assign(self: &mut $W<T>, rhs: Rhs) {
+                    T::$sh_assign(self, rhs)
+                }
+            }
+        )*
+    );
+
+    (
+        @unary $W:ident [$(
+            $Op:ident::$op:ident;
+        )*]
+    ) => (
+        $(
+            impl<T: $Op> $Op for $W<T> {
+                type Output = $W<T::Output>;
+
+                #[inline]
+                fn $op(self: $W<T>) -> $W<T::Output> {
+                    $W::new(T::$op($W::into_inner(self)))
+                }
+            }
+
+            impl<'l, T> $Op for &'l $W<T>
+            where
+                &'l T: $Op,
+            {
+                type Output = $W<<&'l T as $Op>::Output>;
+
+                #[inline]
+                fn $op(self: &'l $W<T>) -> Self::Output {
+                    $W::new(<&'l T>::$op(&**self))
+                }
+            }
+        )*
+    );
+}
+
+OpsTM! {
+    for ManuallyDrop;
+    for MaybeDangling;
+
+    binary [
+        Add::add, AddAssign::add_assign;
+        Sub::sub, SubAssign::sub_assign;
+        Mul::mul, MulAssign::mul_assign;
+        Div::div, DivAssign::div_assign;
+        Rem::rem, RemAssign::rem_assign;
+        BitAnd::bitand, BitAndAssign::bitand_assign;
+        BitOr::bitor, BitOrAssign::bitor_assign;
+        BitXor::bitxor, BitXorAssign::bitxor_assign;
+    ]
+
+    shift [
+        Shl::shl, ShlAssign::shl_assign;
+        Shr::shr, ShrAssign::shr_assign;
+    ]
+
+    unary [
+        Neg::neg;
+        Not::not;
+    ]
+}
@@ -0,0 +1,51 @@
+//! Transparent `Serialize` / `Deserialize` impls: the wrappers (de)serialize
+//! exactly as the wrapped `T` would.
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+impl<T: Serialize> Serialize for ManuallyDrop<T> {
+    #[inline]
+    fn serialize<S: Serializer>(self: &Self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for ManuallyDrop<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+impl<T: Serialize> Serialize for MaybeDangling<T> {
+    #[inline]
+    fn serialize<S: Serializer>(self: &Self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
+/// Borrowed data is supported as well:
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use ::maybe_dangling::MaybeDangling;
+/// use ::serde::{de::value::{BorrowedStrDeserializer, Error}, Deserialize};
+///
+/// let input = String::from("borrowed");
+/// let s = MaybeDangling::<&str>::deserialize(
+///     BorrowedStrDeserializer::<Error>::new(&input),
+/// ).unwrap();
+/// assert_eq!(*s, "borrowed");
+/// # }
+/// ```
+#[cfg_attr(feature = "better-docs", doc(cfg(feature = "serde")))]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MaybeDangling<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
@@ -0,0 +1,62 @@
+//! Forwarding of the `std`-only traits: `Error`, and the `io` ones.
+
+use crate::{manually_drop::JustDerefTM, ManuallyDrop};
+use ::std::{error, io, string::String, vec::Vec};
+
+JustDerefTM! {
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "std")))]
+    impl<T: error::Error> error::Error for ManuallyDrop<T> {
+        fn source(self: &Self) -> Option<&(dyn error::Error + 'static)>;
+    }
+
+    /// ```rust
+    /// use ::maybe_dangling::{ManuallyDrop, MaybeDangling};
+    /// use ::std::{error::Error, io::{self, Read, Write}};
+    ///
+    /// let mut reader = MaybeDangling::new(&b"hello"[..]);
+    /// let mut s = String::new();
+    /// reader.read_to_string(&mut s).unwrap();
+    /// assert_eq!(s, "hello");
+    ///
+    /// let mut writer = ManuallyDrop::new(Vec::new());
+    /// writer.write_all(b"bye").unwrap();
+    /// assert_eq!(*writer, b"bye");
+    /// # unsafe { ManuallyDrop::drop(&mut writer) }
+    ///
+    /// let err = MaybeDangling::new(io::Error::new(io::ErrorKind::Other, "oops"));
+    /// assert!(err.source().is_none());
+    /// assert_eq!(err.to_string(), "oops");
+    /// ```
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "std")))]
+    impl<T: io::Read> io::Read for ManuallyDrop<T> {
+        fn read(self: &mut Self, buf: &mut [u8]) -> io::Result<usize>;
+        fn read_vectored(self: &mut Self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize>;
+        fn read_to_end(self: &mut Self, buf: &mut Vec<u8>) -> io::Result<usize>;
+        fn read_to_string(self: &mut Self, buf: &mut String) -> io::Result<usize>;
+        fn read_exact(self: &mut Self, buf: &mut [u8]) -> io::Result<()>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "std")))]
+    impl<T: io::BufRead> io::BufRead for ManuallyDrop<T> {
+        fn fill_buf(self: &mut Self) -> io::Result<&[u8]>;
+        fn consume(self: &mut Self, amt: usize);
+        fn read_until(self: &mut Self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize>;
+        fn read_line(self: &mut Self, buf: &mut String) -> io::Result<usize>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "std")))]
+    impl<T: io::Write> io::Write for ManuallyDrop<T> {
+        fn write(self: &mut Self, buf: &[u8]) -> io::Result<usize>;
+        fn write_vectored(self: &mut Self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize>;
+        fn flush(self: &mut Self) -> io::Result<()>;
+        fn write_all(self: &mut Self, buf: &[u8]) -> io::Result<()>;
+        fn write_fmt(self: &mut Self, args: ::core::fmt::Arguments<'_>) -> io::Result<()>;
+    }
+
+    #[cfg_attr(feature = "better-docs", doc(cfg(feature = "std")))]
+    impl<T: io::Seek> io::Seek for ManuallyDrop<T> {
+        fn seek(self: &mut Self, pos: io::SeekFrom) -> io::Result<u64>;
+        fn rewind(self: &mut Self) -> io::Result<()>;
+        fn stream_position(self: &mut Self) -> io::Result<u64>;
+    }
+}
@@ -0,0 +1,265 @@
+//! Transpositions between a wrapped array / tuple / `Option` / `Result`, and
+//! the array / tuple / `Option` / `Result` of wrappers: see the docs of
+//! [`MaybeDangling`].
+
+use crate::{ManuallyDrop, MaybeDangling};
+use ::core::{
+    mem::{transmute_copy, ManuallyDrop as StdMD, MaybeUninit as MU},
+    ptr,
+};
+
+macro_rules! TransposeTM {(
+    $(
+        for $W:ident;
+    )*
+) => (
+    $(
+        /// Array transpositions.
+        // Safety: `#[repr(transparent)]` all the way down, and the drop glue
+        // of the source is defused.
+        #[allow(unsafe_code)]
+        impl<T, const N: usize> $W<[T; N]> {
+            /// Transposes a wrapped array into an array of wrappers, in place.
+            #[inline]
+            pub fn transpose_array(slot: $W<[T; N]>) -> [$W<T>; N] {
+                unsafe { transmute_copy(&StdMD::new(slot)) }
+            }
+
+            /// Transposes an array of wrappers into a wrapped array, in place.
+            #[inline]
+            pub fn untranspose_array(array: [$W<T>; N]) -> $W<[T; N]> {
+                unsafe { transmute_copy(&StdMD::new(array)) }
+            }
+        }
+
+        /// `Option` transpositions.
+        impl<T> $W<Option<T>> {
+            /// Transposes a wrapped `Option` into an `Option` of a wrapper.
+            #[inline]
+            pub fn transpose_option(slot: $W<Option<T>>) -> Option<$W<T>> {
+                $W::into_inner(slot).map($W::new)
+            }
+
+            /// Transposes an `Option` of a wrapper into a wrapped `Option`.
+            #[inline]
+            pub fn untranspose_option(option: Option<$W<T>>) -> $W<Option<T>> {
+                $W::new(option.map($W::into_inner))
+            }
+        }
+
+        /// `Result` transpositions.
+        impl<T, E> $W<Result<T, E>> {
+            /// Transposes a wrapped `Result` into a `Result` of wrappers.
+            #[inline]
+            pub fn transpose_result(slot: $W<Result<T, E>>) -> Result<$W<T>, $W<E>> {
+                $W::into_inner(slot).map($W::new).map_err($W::new)
+            }
+
+            /// Transposes a `Result` of wrappers into a wrapped `Result`.
+            #[inline]
+            pub fn untranspose_result(result: Result<$W<T>, $W<E>>) -> $W<Result<T, E>> {
+                $W::new(result.map($W::into_inner).map_err($W::into_inner))
+            }
+        }
+
+        impl<T, const N: usize> From<$W<[T; N]>> for [$W<T>; N] {
+            #[inline]
+            fn from(slot: $W<[T; N]>) -> [$W<T>; N] {
+                $W::transpose_array(slot)
+            }
+        }
+
+        impl<T, const N: usize> From<[$W<T>; N]> for $W<[T; N]> {
+            #[inline]
+            fn from(array: [$W<T>; N]) -> $W<[T; N]> {
+                $W::untranspose_array(array)
+            }
+        }
+
+        impl<T> From<$W<Option<T>>> for Option<$W<T>> {
+            #[inline]
+            fn from(slot: $W<Option<T>>) -> Option<$W<T>> {
+                $W::transpose_option(slot)
+            }
+        }
+
+        impl<T> From<Option<$W<T>>> for $W<Option<T>> {
+            #[inline]
+            fn from(option: Option<$W<T>>) -> $W<Option<T>> {
+                $W::untranspose_option(option)
+            }
+        }
+
+        impl<T, E> From<$W<Result<T, E>>> for Result<$W<T>, $W<E>> {
+            #[inline]
+            fn from(slot: $W<Result<T, E>>) -> Result<$W<T>, $W<E>> {
+                $W::transpose_result(slot)
+            }
+        }
+
+        impl<T, E> From<Result<$W<T>, $W<E>>> for $W<Result<T, E>> {
+            #[inline]
+            fn from(result: Result<$W<T>, $W<E>>) -> $W<Result<T, E>> {
+                $W::untranspose_result(result)
+            }
+        }
+    )*
+)}
+
+TransposeTM! {
+    for ManuallyDrop;
+    for MaybeDangling;
+}
+
+/// The tuples, of arity 1 to 12, which can be transposed through a wrapper:
+/// see [`ManuallyDrop::transpose_tuple()`] and
+/// [`MaybeDangling::transpose_tuple()`].
+///
+/// This trait is sealed.
+pub trait Tuple: Sized + sealed::Tuple {
+    /// `(ManuallyDrop<A>, ManuallyDrop<B>, …)`.
+    type OfManuallyDrop: From<ManuallyDrop<Self>>;
+    /// `(MaybeDangling<A>, MaybeDangling<B>, …)`.
+    type OfMaybeDangling: From<MaybeDangling<Self>>;
+}
+
+/// The tuples, of arity 1 to 12, of [`ManuallyDrop`]s or of
+/// [`MaybeDangling`]s: see [`ManuallyDrop::untranspose_tuple()`] and
+/// [`MaybeDangling::untranspose_tuple()`].
+///
+/// This trait is sealed.
+pub trait TupleOfWrappers: Sized + sealed::TupleOfWrappers {
+    /// `ManuallyDrop<(A, B, …)>` or `MaybeDangling<(A, B, …)>`.
+    type Untransposed;
+}
+
+mod sealed {
+    pub trait Tuple {}
+    pub trait TupleOfWrappers {}
+}
+
+/// Tuple transpositions.
+impl<T: Tuple> ManuallyDrop<T> {
+    /// Transposes a wrapped tuple into a tuple of wrappers, in place.
+    #[inline]
+    pub fn transpose_tuple(slot: ManuallyDrop<T>) -> T::OfManuallyDrop {
+        T::OfManuallyDrop::from(slot)
+    }
+
+    /// Transposes a tuple of wrappers into a wrapped tuple, in place.
+    #[inline]
+    pub fn untranspose_tuple<Tup>(tuple: Tup) -> ManuallyDrop<T>
+    where
+        Tup: TupleOfWrappers<Untransposed = ManuallyDrop<T>>,
+        ManuallyDrop<T>: From<Tup>,
+    {
+        ManuallyDrop::from(tuple)
+    }
+}
+
+/// Tuple transpositions.
+impl<T: Tuple> MaybeDangling<T> {
+    /// Transposes a wrapped tuple into a tuple of wrappers, in place.
+    #[inline]
+    pub fn transpose_tuple(slot: MaybeDangling<T>) -> T::OfMaybeDangling {
+        T::OfMaybeDangling::from(slot)
+    }
+
+    /// Transposes a tuple of wrappers into a wrapped tuple, in place.
+    #[inline]
+    pub fn untranspose_tuple<Tup>(tuple: Tup) -> MaybeDangling<T>
+    where
+        Tup: TupleOfWrappers<Untransposed = MaybeDangling<T>>,
+        MaybeDangling<T>: From<Tup>,
+    {
+        MaybeDangling::from(tuple)
+    }
+}
+
+macro_rules! TransposeTupleTM {(
+    $(
+        ($($A:ident $i:tt),+ $(,)?)
+    )*
+) => (
+    $(
+        impl<$($A),+> sealed::Tuple for ($($A,)+) {}
+
+        impl<$($A),+> Tuple for ($($A,)+) {
+            type OfManuallyDrop = ($(ManuallyDrop<$A>,)+);
+            type OfMaybeDangling = ($(MaybeDangling<$A>,)+);
+        }
+
+        impl<$($A),+> sealed::TupleOfWrappers for ($(ManuallyDrop<$A>,)+) {}
+
+        impl<$($A),+> TupleOfWrappers for ($(ManuallyDrop<$A>,)+) {
+            type Untransposed = ManuallyDrop<($($A,)+)>;
+        }
+
+        impl<$($A),+> sealed::TupleOfWrappers for ($(MaybeDangling<$A>,)+) {}
+
+        impl<$($A),+> TupleOfWrappers for ($(MaybeDangling<$A>,)+) {
+            type Untransposed = MaybeDangling<($($A,)+)>;
+        }
+
+        // Safety: each field is read as a `ManuallyDrop`, whose layout is that
+        // of the field, and `slot` has no drop glue.
+        #[allow(unsafe_code)]
+        impl<$($A),+> From<ManuallyDrop<($($A,)+)>> for ($(ManuallyDrop<$A>,)+) {
+            #[inline]
+            fn from(slot: ManuallyDrop<($($A,)+)>) -> ($(ManuallyDrop<$A>,)+) {
+                let p = ManuallyDrop::as_ptr(&slot);
+                unsafe { ($(ptr::addr_of!((*p).$i).cast::<ManuallyDrop<$A>>().read(),)+) }
+            }
+        }
+
+        // Safety: each field is written as a `ManuallyDrop`, whose layout is
+        // that of the field, so the whole `MU` gets init.
+        #[allow(unsafe_code)]
+        impl<$($A),+> From<($(ManuallyDrop<$A>,)+)> for ManuallyDrop<($($A,)+)> {
+            #[inline]
+            fn from(tuple: ($(ManuallyDrop<$A>,)+)) -> ManuallyDrop<($($A,)+)> {
+                let mut slot = MU::<($($A,)+)>::uninit();
+                let p = slot.as_mut_ptr();
+                unsafe {
+                    $(
+                        ptr::addr_of_mut!((*p).$i).cast::<ManuallyDrop<$A>>().write(tuple.$i);
+                    )+
+                    ManuallyDrop::assume_init_from(slot)
+                }
+            }
+        }
+
+        impl<$($A),+> From<MaybeDangling<($($A,)+)>> for ($(MaybeDangling<$A>,)+) {
+            #[inline]
+            fn from(slot: MaybeDangling<($($A,)+)>) -> ($(MaybeDangling<$A>,)+) {
+                let tuple = <($(ManuallyDrop<$A>,)+)>::from(MaybeDangling::into_manually_drop(slot));
+                ($(ManuallyDrop::into_maybe_dangling(tuple.$i),)+)
+            }
+        }
+
+        impl<$($A),+> From<($(MaybeDangling<$A>,)+)> for MaybeDangling<($($A,)+)> {
+            #[inline]
+            fn from(tuple: ($(MaybeDangling<$A>,)+)) -> MaybeDangling<($($A,)+)> {
+                let slot = ManuallyDrop::<($($A,)+)>::from((
+                    $(MaybeDangling::into_manually_drop(tuple.$i),)+
+                ));
+                ManuallyDrop::into_maybe_dangling(slot)
+            }
+        }
+    )*
+)}
+
+TransposeTupleTM! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}